@@ -0,0 +1,229 @@
+// Packs the images in textures/ into raw rgba, so the game doesn't have
+// to decode them at runtime. Each one becomes a constant in the assets
+// module, named after the file, for Texture::from_packed. The sprite
+// sheets and palettes from the editor's .gce file are exported there
+// too, since the console can't read them back at runtime.
+
+use std::{
+    collections::HashMap,
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use image::ImageFormat;
+use import::Image;
+use serde_json::Value;
+
+// The Gamercade editor's project file
+const GCE: &str = "5r5g4b_color.gce";
+
+// Every palette in a .gce file has this many colors
+const PALETTE_SIZE: usize = 64;
+
+fn main() {
+    let out_dir = std::env::var("OUT_DIR").unwrap();
+
+    let mut assets = String::new();
+    let mut constants = Constants::default();
+    import_textures(&out_dir, &mut assets, &mut constants);
+    import_sprites(&mut assets, &mut constants);
+
+    fs::write(Path::new(&out_dir).join("assets.rs"), assets).unwrap();
+}
+
+// The names of the constants in the assets module, and the files they're from
+#[derive(Default)]
+struct Constants(HashMap<String, PathBuf>);
+
+impl Constants {
+    // Checks the name can be used for a constant, and that no other file has taken it
+    fn add(&mut self, name: &str, path: &Path) {
+        let valid = name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+            && name != "_";
+        if !valid {
+            panic!(
+                "can't import {}: {name} isn't a valid constant name, rename the file",
+                path.display()
+            );
+        }
+
+        if let Some(other) = self.0.insert(name.to_string(), path.to_path_buf()) {
+            panic!(
+                "can't import {}: {} is already the constant {name}, rename one of them",
+                path.display(),
+                other.display()
+            );
+        }
+    }
+}
+
+// Decodes a png, ppm or tga file to rgba
+fn decode(extension: &str, bytes: &[u8]) -> Result<Image, String> {
+    let format = ImageFormat::from_extension(extension)
+        .filter(|format| {
+            matches!(
+                format,
+                ImageFormat::Png | ImageFormat::Pnm | ImageFormat::Tga
+            )
+        })
+        .ok_or_else(|| format!("unsupported image format .{extension}"))?;
+    let rgba = image::load_from_memory_with_format(bytes, format)
+        .map_err(|error| error.to_string())?
+        .into_rgba8();
+
+    Ok(Image {
+        width: rgba.width() as usize,
+        height: rgba.height() as usize,
+        rgba: rgba.into_raw(),
+    })
+}
+
+fn import_textures(out_dir: &str, assets: &mut String, constants: &mut Constants) {
+    println!("cargo:rerun-if-changed=textures");
+
+    let mut paths = fs::read_dir("textures")
+        .map(|entries| {
+            entries
+                .map(|entry| entry.unwrap().path())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    paths.sort();
+
+    for path in paths {
+        let (Some(name), Some(extension)) = (path.file_stem(), path.extension()) else {
+            continue;
+        };
+        let name = name.to_string_lossy();
+        let extension = extension.to_string_lossy();
+
+        println!("cargo:rerun-if-changed={}", path.display());
+
+        let image = fs::read(&path)
+            .map_err(|error| error.to_string())
+            .and_then(|bytes| decode(&extension, &bytes))
+            .and_then(|image| image.validate().map(|_| image))
+            .unwrap_or_else(|error| panic!("can't import {}: {error}", path.display()));
+
+        let packed = Path::new(out_dir).join(format!("{name}.texture"));
+        fs::write(&packed, image.pack()).unwrap();
+
+        let constant = name
+            .chars()
+            .map(|c| match c.is_ascii_alphanumeric() {
+                true => c.to_ascii_uppercase(),
+                false => '_',
+            })
+            .collect::<String>();
+        constants.add(&constant, &path);
+
+        writeln!(
+            assets,
+            "// {}x{} from {}\npub const {constant}: &[u8] = include_bytes!({:?});",
+            image.width,
+            image.height,
+            path.display(),
+            packed.display(),
+        )
+        .unwrap();
+    }
+}
+
+fn import_sprites(assets: &mut String, constants: &mut Constants) {
+    println!("cargo:rerun-if-changed={GCE}");
+
+    constants.add("PALETTES", Path::new(GCE));
+    constants.add("SPRITE_SHEETS", Path::new(GCE));
+
+    let sprites = fs::read_to_string(GCE)
+        .map_err(|error| error.to_string())
+        .and_then(|text| serde_json::from_str::<Value>(&text).map_err(|error| error.to_string()))
+        .and_then(|gce| sprites(&gce["graphics"]))
+        .unwrap_or_else(|error| panic!("can't import {GCE}: {error}"));
+
+    assets.push_str(&sprites);
+}
+
+// Writes out the palettes and sprite sheets in the graphics section
+fn sprites(graphics: &Value) -> Result<String, String> {
+    let list = |value: &Value, what: &str| {
+        value
+            .as_array()
+            .cloned()
+            .ok_or_else(|| format!("{what} isn't a list"))
+    };
+
+    let mut out = String::new();
+    writeln!(
+        out,
+        "// The palettes from {GCE}, with colors as 0xRRGGBBAA\n\
+         pub const PALETTES: &[[u32; {PALETTE_SIZE}]] = &["
+    )
+    .unwrap();
+    for palette in list(&graphics["palettes"], "palettes")? {
+        let name = palette["name"].as_str().unwrap_or_default();
+        let colors = list(&palette["palette"], "a palette")?
+            .iter()
+            .map(|color| {
+                color
+                    .as_str()
+                    .and_then(|color| u32::from_str_radix(color, 16).ok())
+                    .map(|color| format!("{color:#010x}"))
+                    .ok_or_else(|| format!("palette {name} has a color which isn't hex"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if colors.len() != PALETTE_SIZE {
+            return Err(format!(
+                "palette {name} has {} colors instead of {PALETTE_SIZE}",
+                colors.len()
+            ));
+        }
+        writeln!(out, "    // {name}\n    [{}],", colors.join(", ")).unwrap();
+    }
+    writeln!(
+        out,
+        "];\n// The sprite sheets from {GCE}\n\
+         pub const SPRITE_SHEETS: &[SpriteSheet] = &["
+    )
+    .unwrap();
+    for sheet in list(&graphics["sprite_sheets"], "sprite_sheets")? {
+        let name = sheet["name"].as_str().unwrap_or_default();
+        let sheet = &sheet["sprite_sheet"];
+        let size = |field: &str| {
+            sheet[field]
+                .as_u64()
+                .map(|size| size as usize)
+                .ok_or_else(|| format!("sprite sheet {name} has no {field}"))
+        };
+        let (width, height, count) = (size("width")?, size("height")?, size("count")?);
+
+        let sprites = sheet["sprites"]
+            .as_str()
+            .and_then(|sprites| BASE64.decode(sprites).ok())
+            .ok_or_else(|| format!("sprite sheet {name} has no base64 sprites"))?;
+        if sprites.len() != width * height * count {
+            return Err(format!(
+                "sprite sheet {name} has {} pixels instead of {count} {width}x{height} sprites",
+                sprites.len()
+            ));
+        }
+        if sprites.iter().any(|&index| index as usize >= PALETTE_SIZE) {
+            return Err(format!(
+                "sprite sheet {name} uses colors past the end of the palette"
+            ));
+        }
+
+        writeln!(
+            out,
+            "    // {name}\n    SpriteSheet {{ width: {width}, height: {height}, count: {count}, sprites: &{sprites:?} }},"
+        )
+        .unwrap();
+    }
+    writeln!(out, "];").unwrap();
+
+    Ok(out)
+}
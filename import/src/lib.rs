@@ -0,0 +1,73 @@
+// Checks and packs the images in textures/ for build.rs, into the
+// format read by Texture::from_packed. This is its own crate so the
+// build script can use it. Its tests are only run by
+// `cargo test --workspace`, a plain `cargo test` skips them.
+
+// Textures have to be a power of two on each side, up to this size
+pub const MAX_SIZE: usize = 1024;
+
+// Pixels decoded from an image file, as 8 bit rgba from the top left
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    pub rgba: Vec<u8>,
+}
+
+impl Image {
+    // Checks the image can be used as a texture
+    pub fn validate(&self) -> Result<(), String> {
+        let (width, height) = (self.width, self.height);
+
+        if !width.is_power_of_two() || !height.is_power_of_two() {
+            return Err(format!(
+                "{width}x{height} is not a power of two on each side"
+            ));
+        }
+        if width > MAX_SIZE || height > MAX_SIZE {
+            return Err(format!(
+                "{width}x{height} is larger than {MAX_SIZE}x{MAX_SIZE}"
+            ));
+        }
+
+        Ok(())
+    }
+
+    // The width and height as little endian u32s, followed by the pixels
+    pub fn pack(&self) -> Vec<u8> {
+        let mut packed = Vec::with_capacity(8 + self.rgba.len());
+        packed.extend((self.width as u32).to_le_bytes());
+        packed.extend((self.height as u32).to_le_bytes());
+        packed.extend(&self.rgba);
+        packed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn image(width: usize, height: usize) -> Image {
+        Image {
+            width,
+            height,
+            rgba: (0..width * height * 4).map(|byte| byte as u8).collect(),
+        }
+    }
+
+    #[test]
+    fn textures_must_be_powers_of_two() {
+        assert!(image(64, 8).validate().is_ok());
+        assert!(image(1, 1).validate().is_ok());
+        assert!(image(48, 64).validate().is_err());
+        assert!(image(0, 0).validate().is_err());
+        assert!(image(2048, 2048).validate().is_err());
+    }
+
+    #[test]
+    fn packed_images_start_with_their_size() {
+        let packed = image(1, 2).pack();
+
+        assert_eq!(packed[..8], [1, 0, 0, 0, 2, 0, 0, 0]);
+        assert_eq!(packed[8..], [0, 1, 2, 3, 4, 5, 6, 7]);
+    }
+}
@@ -0,0 +1,32 @@
+// Textures imported from textures/ by build.rs, ready for Texture::from_packed,
+// and the sprite sheets and palettes from the editor for Texture::from_sprite
+include!(concat!(env!("OUT_DIR"), "/assets.rs"));
+
+// Sprites of the same size, stored one after the other
+// as a palette index per pixel
+pub struct SpriteSheet {
+    pub width: usize,
+    pub height: usize,
+    pub count: usize,
+    pub sprites: &'static [u8],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{texture::Texture, types::Color};
+
+    #[test]
+    fn sprites_are_exported_from_the_editor() {
+        let sheet = &SPRITE_SHEETS[0];
+        assert_eq!((sheet.width, sheet.height, sheet.count), (8, 8, 1));
+        assert_eq!(PALETTES.len(), 256);
+
+        // The sprite shows every color of the first palette in order
+        let texture = Texture::from_sprite(8, 8, sheet.sprites, 0, &PALETTES[0]);
+        assert_eq!(texture.texel(0, 0), Color::new(0, 0, 0));
+        assert_eq!(texture.texel(1, 0), Color::new(0, 0, 0x11));
+        assert_eq!(texture.texel(0, 4), Color::new(0, 0x11, 0));
+        assert_eq!(texture.texel(7, 7), Color::new(0, 0x19, 0xff));
+    }
+}
@@ -0,0 +1,94 @@
+use crate::types::Color;
+
+// How a shaded pixel is combined with the color already in the color buffer.
+// The source alpha controls how strongly each mode is applied.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum BlendMode {
+    // Overwrites the destination, ignoring alpha
+    #[default]
+    Opaque,
+    // Standard transparency, src * a + dst * (1 - a)
+    Alpha,
+    // Brightens the destination, for glows and particles
+    Additive,
+    // Darkens the destination, for shadows and tinted glass
+    Multiply,
+}
+
+impl BlendMode {
+    pub fn blend(self, src: Color, dst: Color) -> Color {
+        let alpha = src.a as u32;
+
+        let channel = |src: u8, dst: u8| -> u8 {
+            let (src, dst) = (src as u32, dst as u32);
+            let out = match self {
+                Self::Opaque => src,
+                Self::Alpha => (src * alpha + dst * (255 - alpha)) / 255,
+                Self::Additive => (dst + (src * alpha) / 255).min(255),
+                Self::Multiply => (dst * (src * alpha + 255 * (255 - alpha))) / (255 * 255),
+            };
+            out as u8
+        };
+
+        Color::rgba(
+            channel(src.r, dst.r),
+            channel(src.g, dst.g),
+            channel(src.b, dst.b),
+            dst.a,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn modes_blend_known_colors() {
+        let half_red = Color::rgba(255, 0, 0, 128);
+        let blue = Color::new(0, 0, 255);
+
+        assert_eq!(
+            BlendMode::Opaque.blend(half_red, blue),
+            Color::new(255, 0, 0)
+        );
+        assert_eq!(
+            BlendMode::Alpha.blend(half_red, blue),
+            Color::new(128, 0, 127)
+        );
+
+        // Additive scales the source by alpha, and saturates
+        let src = Color::rgba(100, 200, 50, 128);
+        let dst = Color::new(200, 100, 0);
+        assert_eq!(
+            BlendMode::Additive.blend(src, dst),
+            Color::new(250, 200, 25)
+        );
+        assert_eq!(
+            BlendMode::Additive.blend(Color::new(255, 255, 255), dst),
+            Color::new(255, 255, 255)
+        );
+
+        // Multiply fades towards leaving the destination as alpha drops
+        let src = Color::new(128, 255, 0);
+        let dst = Color::new(200, 200, 200);
+        assert_eq!(BlendMode::Multiply.blend(src, dst), Color::new(100, 200, 0));
+        assert_eq!(
+            BlendMode::Multiply.blend(Color::rgba(128, 255, 0, 0), dst),
+            dst
+        );
+    }
+
+    #[test]
+    fn destination_alpha_is_kept() {
+        let dst = Color::rgba(10, 20, 30, 40);
+        for mode in [
+            BlendMode::Opaque,
+            BlendMode::Alpha,
+            BlendMode::Additive,
+            BlendMode::Multiply,
+        ] {
+            assert_eq!(mode.blend(Color::new(255, 0, 0), dst).a, 40, "{mode:?}");
+        }
+    }
+}
@@ -0,0 +1,235 @@
+use crate::types::{Line, Triangle, TriangleVertex};
+
+// How far past the screen edges, as a multiple of the screen size,
+// triangles are allowed to extend before they get clipped on x and y.
+// Anything in between is trimmed by the rasterizer instead.
+pub const DEFAULT_GUARD_BAND: f32 = 2.0;
+
+// The largest guard band allowed. Past this, triangles are barely clipped
+// on x and y at all, and the rasterizers would have to step across edges
+// from vertices far off screen. Even on a 4096 pixel wide screen this
+// keeps vertices within 2^20 pixels, where an f32 still holds positions
+// to the 1/16th of a pixel the edge function rasterizer snaps them to.
+pub const MAX_GUARD_BAND: f32 = 16.0;
+
+// The six planes bounding the clip space view volume.
+// A vertex is inside the volume when -w <= x, y, z <= w
+#[derive(Clone, Copy)]
+enum ClipPlane {
+    Left,
+    Right,
+    Bottom,
+    Top,
+    Near,
+    Far,
+}
+
+impl ClipPlane {
+    const ALL: [Self; 6] = [
+        Self::Near,
+        Self::Far,
+        Self::Left,
+        Self::Right,
+        Self::Bottom,
+        Self::Top,
+    ];
+
+    // Signed distance from the plane, positive values are inside.
+    // The side planes are pushed out by the guard band.
+    fn distance<const D: usize>(self, vertex: &TriangleVertex<D>, guard_band: f32) -> f32 {
+        let p = &vertex.position;
+        match self {
+            Self::Left => p.w * guard_band + p.x,
+            Self::Right => p.w * guard_band - p.x,
+            Self::Bottom => p.w * guard_band + p.y,
+            Self::Top => p.w * guard_band - p.y,
+            Self::Near => p.w + p.z,
+            Self::Far => p.w - p.z,
+        }
+    }
+}
+
+// Clips triangles and lines in homogeneous clip space, before the perspective divide.
+// Holds on to its working buffers so clipping doesn't allocate every frame.
+pub struct Clipper<const D: usize> {
+    polygon: Vec<TriangleVertex<D>>,
+    scratch: Vec<TriangleVertex<D>>,
+    guard_band: f32,
+}
+
+impl<const D: usize> Default for Clipper<D> {
+    fn default() -> Self {
+        Self {
+            polygon: Vec::new(),
+            scratch: Vec::new(),
+            guard_band: DEFAULT_GUARD_BAND,
+        }
+    }
+}
+
+impl<const D: usize> Clipper<D> {
+    // Clamped between the screen itself and MAX_GUARD_BAND
+    pub fn set_guard_band(&mut self, guard_band: f32) {
+        self.guard_band = guard_band.clamp(1.0, MAX_GUARD_BAND);
+    }
+
+    // Trims the line to the part inside of the view volume, if any.
+    // Lines are walked a pixel at a time and can't overflow like
+    // triangles, so they are clipped to the screen itself.
+    pub fn clip_line(&self, line: Line<D>) -> Option<Line<D>> {
+        let [a, b] = &line.vertices;
+        let (mut t_start, mut t_end) = (0.0f32, 1.0f32);
+
+        for plane in ClipPlane::ALL {
+            let distance_a = plane.distance(a, 1.0);
+            let distance_b = plane.distance(b, 1.0);
+
+            match (distance_a < 0.0, distance_b < 0.0) {
+                (true, true) => return None,
+                (true, false) => {
+                    t_start = t_start.max(distance_a / (distance_a - distance_b));
+                }
+                (false, true) => {
+                    t_end = t_end.min(distance_a / (distance_a - distance_b));
+                }
+                (false, false) => {}
+            }
+        }
+
+        if t_start > t_end {
+            return None;
+        }
+
+        if t_start == 0.0 && t_end == 1.0 {
+            return Some(line);
+        }
+
+        Some(Line {
+            vertices: [a.lerp(b, t_start), a.lerp(b, t_end)],
+        })
+    }
+
+    // Clips the triangle against the view volume and pushes the
+    // visible part, if any, into output as a fan of triangles.
+    pub fn clip(&mut self, triangle: Triangle<D>, output: &mut Vec<Triangle<D>>) {
+        // Trivially reject triangles which are completely off screen,
+        // and accept the ones which fit inside of the guard band
+        let mut fully_inside = true;
+        for plane in ClipPlane::ALL {
+            let vertices = &triangle.vertices;
+
+            if vertices
+                .iter()
+                .all(|vertex| plane.distance(vertex, 1.0) < 0.0)
+            {
+                return;
+            }
+
+            if vertices
+                .iter()
+                .any(|vertex| plane.distance(vertex, self.guard_band) < 0.0)
+            {
+                fully_inside = false;
+            }
+        }
+
+        if fully_inside {
+            output.push(triangle);
+            return;
+        }
+
+        // Sutherland-Hodgman, one plane at a time
+        self.polygon.clear();
+        self.polygon.extend(triangle.vertices);
+
+        for plane in ClipPlane::ALL {
+            self.scratch.clear();
+
+            for (index, current) in self.polygon.iter().enumerate() {
+                let next = &self.polygon[(index + 1) % self.polygon.len()];
+                let current_distance = plane.distance(current, self.guard_band);
+                let next_distance = plane.distance(next, self.guard_band);
+
+                if current_distance >= 0.0 {
+                    self.scratch.push(current.clone());
+                }
+
+                if (current_distance >= 0.0) != (next_distance >= 0.0) {
+                    let alpha = current_distance / (current_distance - next_distance);
+                    self.scratch.push(current.lerp(next, alpha));
+                }
+            }
+
+            std::mem::swap(&mut self.polygon, &mut self.scratch);
+
+            if self.polygon.len() < 3 {
+                return;
+            }
+        }
+
+        // Triangulate the clipped polygon as a fan around the first vertex
+        output.extend(self.polygon.windows(2).skip(1).map(|edge| Triangle {
+            vertices: [self.polygon[0].clone(), edge[0].clone(), edge[1].clone()],
+        }));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::{Vector1, Vector4};
+
+    use super::*;
+
+    fn vertex(x: f32, z: f32, parameter: f32) -> TriangleVertex<1> {
+        TriangleVertex {
+            position: Vector4::new(x, 0.0, z, 1.0),
+            parameters: Vector1::new(parameter),
+        }
+    }
+
+    #[test]
+    fn triangles_are_cut_at_the_near_plane() {
+        let mut clipper = Clipper::default();
+        let mut output = Vec::new();
+
+        // The last vertex is past the near plane, at z < -w
+        let triangle = Triangle {
+            vertices: [
+                vertex(0.0, 0.0, 0.0),
+                vertex(0.5, 0.0, 1.0),
+                vertex(0.0, -2.0, 3.0),
+            ],
+        };
+        clipper.clip(triangle, &mut output);
+
+        // What is left is a quad, split into a fan around the first vertex
+        let vertices = output
+            .iter()
+            .map(|triangle| {
+                triangle
+                    .vertices
+                    .clone()
+                    .map(|vertex| (vertex.position.x, vertex.position.z, vertex.parameters.x))
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vertices,
+            [
+                [(0.0, 0.0, 0.0), (0.5, 0.0, 1.0), (0.25, -1.0, 2.0)],
+                [(0.0, 0.0, 0.0), (0.25, -1.0, 2.0), (0.0, -1.0, 1.5)],
+            ]
+        );
+
+        // Triangles fully outside are dropped
+        output.clear();
+        let triangle = Triangle {
+            vertices: [
+                vertex(0.0, -2.0, 0.0),
+                vertex(0.5, -2.0, 1.0),
+                vertex(0.0, -3.0, 3.0),
+            ],
+        };
+        clipper.clip(triangle, &mut output);
+        assert!(output.is_empty());
+    }
+}
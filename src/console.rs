@@ -0,0 +1,121 @@
+use gamercade_rs::prelude::{self as gc, GraphicsParameters};
+
+// The parts of the console the renderer talks to. Rendering happens in
+// the Gpu's buffers, this is only needed to get a finished frame on screen
+pub trait Console {
+    fn width(&self) -> usize;
+    fn height(&self) -> usize;
+    fn clear_screen(&mut self, color: GraphicsParameters);
+    fn set_pixel(&mut self, color: GraphicsParameters, x: i32, y: i32);
+    fn rect_filled(&mut self, color: GraphicsParameters, x: i32, y: i32, width: u32, height: u32);
+}
+
+// The real console, through the Gamercade api
+pub struct Gamercade;
+
+impl Console for Gamercade {
+    fn width(&self) -> usize {
+        gc::width()
+    }
+
+    fn height(&self) -> usize {
+        gc::height()
+    }
+
+    fn clear_screen(&mut self, color: GraphicsParameters) {
+        gc::clear_screen(color)
+    }
+
+    fn set_pixel(&mut self, color: GraphicsParameters, x: i32, y: i32) {
+        gc::set_pixel(color, x, y)
+    }
+
+    fn rect_filled(&mut self, color: GraphicsParameters, x: i32, y: i32, width: u32, height: u32) {
+        gc::rect_filled(color, x, y, width, height)
+    }
+}
+
+// A console screen kept in memory, so frames can be
+// presented and checked without the Gamercade runtime
+#[cfg(test)]
+pub struct HeadlessConsole {
+    pub width: usize,
+    pub height: usize,
+    pub screen: Box<[GraphicsParameters]>,
+}
+
+#[cfg(test)]
+impl HeadlessConsole {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            screen: vec![GraphicsParameters::default(); width * height].into_boxed_slice(),
+        }
+    }
+
+    pub fn get_pixel(&self, x: usize, y: usize) -> GraphicsParameters {
+        self.screen[x + y * self.width]
+    }
+}
+
+#[cfg(test)]
+impl Console for HeadlessConsole {
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    fn height(&self) -> usize {
+        self.height
+    }
+
+    fn clear_screen(&mut self, color: GraphicsParameters) {
+        self.screen.iter_mut().for_each(|pixel| *pixel = color);
+    }
+
+    fn set_pixel(&mut self, color: GraphicsParameters, x: i32, y: i32) {
+        if (0..self.width as i32).contains(&x) && (0..self.height as i32).contains(&y) {
+            self.screen[x as usize + y as usize * self.width] = color;
+        }
+    }
+
+    fn rect_filled(&mut self, color: GraphicsParameters, x: i32, y: i32, width: u32, height: u32) {
+        for y in y..y + height as i32 {
+            for x in x..x + width as i32 {
+                self.set_pixel(color, x, y);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{gpu::Gpu, types::Color};
+
+    #[test]
+    fn present_scales_to_the_screen() {
+        let mut gpu = Gpu::new(2, 2);
+        gpu.clear_color_buffer(Color::new(0, 0, 0));
+        let red = Color::new(255, 0, 0);
+        gpu.color_buffer.set(1, 0, red);
+
+        let mut console = HeadlessConsole::new(4, 4);
+        gpu.present(&mut console);
+
+        for y in 0..4 {
+            for x in 0..4 {
+                let expected = if x >= 2 && y < 2 {
+                    red
+                } else {
+                    Color::new(0, 0, 0)
+                };
+                assert_eq!(
+                    console.get_pixel(x, y).0,
+                    expected.to_graphics_params().0,
+                    "pixel {x}, {y}"
+                );
+            }
+        }
+    }
+}
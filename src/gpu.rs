@@ -1,51 +1,450 @@
+use crate::{
+    blend::BlendMode,
+    console::Console,
+    shaders::FrameUniforms,
+    types::{Color, Rect},
+};
+
 pub struct Gpu {
     pub z_buffer: ZBuffer,
+    pub color_buffer: ColorBuffer,
 }
 
 impl Gpu {
-    pub fn new(screen_width: usize, screen_height: usize) -> Self {
+    // Creates a Gpu which renders at the given resolution. This doesn't
+    // need to match the console, the frame is scaled up when presented.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            z_buffer: ZBuffer::new(width, height),
+            color_buffer: ColorBuffer::new(width, height),
+        }
+    }
+
+    // Changes the render resolution, the buffers are cleared. The frame
+    // uniforms drawn with get a projection for the new aspect ratio, and
+    // keep their camera.
+    pub fn resize(&mut self, width: usize, height: usize, frame_uniforms: &mut FrameUniforms) {
+        *self = Self::new(width, height);
+        *frame_uniforms = FrameUniforms {
+            view: frame_uniforms.view,
+            ..FrameUniforms::new(width, height)
+        };
+    }
+
+    pub fn size(&self) -> (usize, usize) {
+        (self.color_buffer.width, self.color_buffer.height)
+    }
+
+    pub fn clear_z_buffer(&mut self, depth_state: &DepthState) {
+        self.z_buffer.clear(depth_state.clear_value)
+    }
+
+    pub fn clear_color_buffer(&mut self, color: Color) {
+        self.color_buffer.clear(color)
+    }
+
+    pub fn render_target(&mut self, depth_state: DepthState) -> RenderTarget<'_> {
+        RenderTarget {
+            color_buffer: &mut self.color_buffer,
+            z_buffer: &mut self.z_buffer,
+            blend_mode: BlendMode::Opaque,
+            depth_state,
+        }
+    }
+
+    // Uploads the finished frame to the console, scaling
+    // it up to fill the screen if the resolutions differ
+    pub fn present(&self, console: &mut impl Console) {
+        self.color_buffer.present(console)
+    }
+}
+
+// The buffers that rasterized pixels are written to,
+// and how the pixels are written into them
+pub struct RenderTarget<'a> {
+    pub color_buffer: &'a mut ColorBuffer,
+    pub z_buffer: &'a mut ZBuffer,
+    pub blend_mode: BlendMode,
+    pub depth_state: DepthState,
+}
+
+impl RenderTarget<'_> {
+    pub fn with_blend_mode(mut self, blend_mode: BlendMode) -> Self {
+        self.blend_mode = blend_mode;
+        self
+    }
+
+    pub fn size(&self) -> (usize, usize) {
+        (self.color_buffer.width, self.color_buffer.height)
+    }
+
+    // Another target writing to the same buffers in the same way,
+    // for passing on to several draws
+    pub fn reborrow(&mut self) -> RenderTarget<'_> {
+        RenderTarget {
+            color_buffer: self.color_buffer,
+            z_buffer: self.z_buffer,
+            blend_mode: self.blend_mode,
+            depth_state: self.depth_state,
+        }
+    }
+}
+
+// Decides if a pixel passes the depth test by comparing
+// its depth against the value stored in the z buffer
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum DepthCompare {
+    #[default]
+    Less,
+    LessEqual,
+    Greater,
+    Always,
+    Never,
+}
+
+impl DepthCompare {
+    pub fn passes(self, value: f32, stored: f32) -> bool {
+        match self {
+            Self::Less => value < stored,
+            Self::LessEqual => value <= stored,
+            Self::Greater => value > stored,
+            Self::Always => true,
+            Self::Never => false,
+        }
+    }
+}
+
+// How draws read and write the z buffer
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct DepthState {
+    pub compare: DepthCompare,
+    // Disable to depth test without hiding anything drawn later,
+    // like decals, overlays and transparent geometry
+    pub write: bool,
+    // Projected depth goes from -1 at the near plane to 1 at the far plane,
+    // and is remapped to this range before testing. Use (1.0, 1.0) to push
+    // skyboxes behind everything
+    pub range: (f32, f32),
+    // The value the z buffer is cleared to
+    pub clear_value: f32,
+}
+
+impl Default for DepthState {
+    fn default() -> Self {
+        Self {
+            compare: DepthCompare::Less,
+            write: true,
+            range: (0.0, 1.0),
+            clear_value: f32::INFINITY,
+        }
+    }
+}
+
+impl DepthState {
+    // Depth state for geometry which is tested, but doesn't write depth
+    pub fn read_only() -> Self {
+        Self {
+            write: false,
+            ..Self::default()
+        }
+    }
+
+    // Remaps a projected depth value into the depth range
+    pub fn map_depth(&self, depth: f32) -> f32 {
+        let (near, far) = self.range;
+        near + (depth + 1.0) * 0.5 * (far - near)
+    }
+}
+
+pub struct ColorBuffer {
+    buffer: Box<[Color]>,
+    clear_color: Color,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl ColorBuffer {
+    pub fn new(width: usize, height: usize) -> Self {
+        let clear_color = Color::new(0, 0, 0);
         Self {
-            z_buffer: ZBuffer::new(screen_width, screen_width),
+            width,
+            height,
+            clear_color,
+            buffer: (0..width * height)
+                .map(|_| clear_color)
+                .collect::<Vec<_>>()
+                .into_boxed_slice(),
         }
     }
 
-    pub fn clear_z_buffer(&mut self) {
-        self.z_buffer.clear()
+    fn clear(&mut self, color: Color) {
+        self.clear_color = color;
+        self.buffer.iter_mut().for_each(|c| *c = color);
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Color {
+        self.buffer[x + (y * self.width)]
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, color: Color) {
+        self.buffer[x + (y * self.width)] = color;
+    }
+
+    #[cfg(test)]
+    pub fn pixels(&self) -> &[Color] {
+        &self.buffer
+    }
+
+    // Encodes the buffer as a binary PPM image, for the golden tests
+    #[cfg(test)]
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut out = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        out.extend(
+            self.buffer
+                .iter()
+                .flat_map(|color| [color.r, color.g, color.b]),
+        );
+        out
+    }
+
+    // Every set_pixel is a call out of the wasm module, so
+    // clear the screen first and skip the background pixels
+    fn present(&self, console: &mut impl Console) {
+        let clear = self.clear_color.to_graphics_params();
+        console.clear_screen(clear);
+
+        let (screen_width, screen_height) = (console.width(), console.height());
+        let scaled = screen_width != self.width || screen_height != self.height;
+
+        for (index, color) in self.buffer.iter().enumerate() {
+            let color = color.to_graphics_params();
+            if color.0 == clear.0 {
+                continue;
+            }
+
+            let x = index % self.width;
+            let y = index / self.width;
+
+            if !scaled {
+                console.set_pixel(color, x as i32, y as i32);
+                continue;
+            }
+
+            // Each pixel covers a block of screen pixels
+            let x_start = x * screen_width / self.width;
+            let x_end = (x + 1) * screen_width / self.width;
+            let y_start = y * screen_height / self.height;
+            let y_end = (y + 1) * screen_height / self.height;
+
+            if x_start < x_end && y_start < y_end {
+                console.rect_filled(
+                    color,
+                    x_start as i32,
+                    y_start as i32,
+                    (x_end - x_start) as u32,
+                    (y_end - y_start) as u32,
+                );
+            }
+        }
     }
 }
 
 pub struct ZBuffer {
     buffer: Box<[f32]>,
-    pub screen_width: usize,
-    pub screen_height: usize,
+    pub width: usize,
+    pub height: usize,
+    // Position of the top left of the buffer on screen,
+    // only non zero for tile local buffers
+    x_offset: usize,
+    y_offset: usize,
 }
 
 impl ZBuffer {
-    fn new(screen_width: usize, screen_height: usize) -> Self {
+    pub fn new(width: usize, height: usize) -> Self {
         Self {
-            screen_width,
-            screen_height,
-            buffer: (0..screen_width * screen_height)
+            width,
+            height,
+            x_offset: 0,
+            y_offset: 0,
+            buffer: (0..width * height)
                 .map(|_| f32::INFINITY)
                 .collect::<Vec<_>>()
                 .into_boxed_slice(),
         }
     }
 
-    fn clear(&mut self) {
-        self.buffer.iter_mut().for_each(|d| *d = f32::INFINITY);
+    fn clear(&mut self, value: f32) {
+        self.buffer.iter_mut().for_each(|d| *d = value);
     }
 
-    // Returns true if the value was < the target value
-    // and the pixel should be drawn
-    pub fn test_and_set(&mut self, x: usize, y: usize, value: f32) -> bool {
-        let entry = &mut self.buffer[x + (y * self.screen_width)];
+    // The screen pixels covered by this buffer
+    pub fn bounds(&self) -> Rect {
+        Rect {
+            x_start: self.x_offset as i32,
+            y_start: self.y_offset as i32,
+            x_end: (self.x_offset + self.width) as i32,
+            y_end: (self.y_offset + self.height) as i32,
+        }
+    }
+
+    // Moves this tile local buffer over the given region of the
+    // source buffer, and copies in the depth values underneath it.
+    // The region must fit inside of the tile's original size.
+    pub fn load_tile(&mut self, source: &ZBuffer, region: Rect) {
+        self.x_offset = region.x_start as usize;
+        self.y_offset = region.y_start as usize;
+        self.width = (region.x_end - region.x_start) as usize;
+        self.height = (region.y_end - region.y_start) as usize;
+
+        for row in 0..self.height {
+            let source_start = self.x_offset + (self.y_offset + row) * source.width;
+            let tile_start = row * self.width;
+            self.buffer[tile_start..tile_start + self.width]
+                .copy_from_slice(&source.buffer[source_start..source_start + self.width]);
+        }
+    }
 
-        if value < *entry {
-            *entry = value;
+    // Writes the depth values of this tile local buffer back to the target
+    pub fn store_tile(&self, target: &mut ZBuffer) {
+        for row in 0..self.height {
+            let target_start = self.x_offset + (self.y_offset + row) * target.width;
+            let tile_start = row * self.width;
+            target.buffer[target_start..target_start + self.width]
+                .copy_from_slice(&self.buffer[tile_start..tile_start + self.width]);
+        }
+    }
+
+    #[cfg(test)]
+    pub fn get(&self, x: usize, y: usize) -> f32 {
+        self.buffer[(x - self.x_offset) + ((y - self.y_offset) * self.width)]
+    }
+
+    // Returns true if the value passes the depth test and the pixel
+    // should be drawn, storing the value if depth writes are enabled
+    pub fn test_and_set(&mut self, x: usize, y: usize, value: f32, state: &DepthState) -> bool {
+        let entry = &mut self.buffer[(x - self.x_offset) + ((y - self.y_offset) * self.width)];
+
+        if state.compare.passes(value, *entry) {
+            if state.write {
+                *entry = value;
+            }
             true
         } else {
             false
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::{Transform3, Translation3};
+
+    use super::*;
+
+    #[test]
+    fn depth_compares_and_writes() {
+        use DepthCompare::*;
+
+        // Whether 0.25, 0.5 and 0.75 pass against a stored 0.5
+        let table = [
+            (Less, [true, false, false]),
+            (LessEqual, [true, true, false]),
+            (Greater, [false, false, true]),
+            (Always, [true, true, true]),
+            (Never, [false, false, false]),
+        ];
+
+        for (compare, expected) in table {
+            for write in [true, false] {
+                let state = DepthState {
+                    compare,
+                    write,
+                    ..DepthState::default()
+                };
+
+                for (value, passes) in [0.25, 0.5, 0.75].into_iter().zip(expected) {
+                    let mut z_buffer = ZBuffer::new(1, 1);
+                    z_buffer.clear(0.5);
+
+                    assert_eq!(
+                        z_buffer.test_and_set(0, 0, value, &state),
+                        passes,
+                        "{compare:?} {value}"
+                    );
+
+                    // Depth is only stored by passing pixels with writes on
+                    let stored = match write && passes {
+                        true => value,
+                        false => 0.5,
+                    };
+                    assert_eq!(z_buffer.get(0, 0), stored, "{compare:?} {value} {write}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn depth_range_and_clear_value() {
+        let default = DepthState::default();
+        assert_eq!(default.map_depth(-1.0), 0.0);
+        assert_eq!(default.map_depth(0.0), 0.5);
+        assert_eq!(default.map_depth(1.0), 1.0);
+
+        let narrow = DepthState {
+            range: (0.25, 0.75),
+            ..default
+        };
+        assert_eq!(narrow.map_depth(-1.0), 0.25);
+        assert_eq!(narrow.map_depth(0.0), 0.5);
+
+        // Reversed depth, cleared to the near value and keeping the largest
+        let reversed = DepthState {
+            compare: DepthCompare::Greater,
+            range: (1.0, 0.0),
+            clear_value: 0.0,
+            ..default
+        };
+        let mut gpu = Gpu::new(2, 2);
+        gpu.clear_z_buffer(&reversed);
+        assert_eq!(gpu.z_buffer.get(1, 1), 0.0);
+
+        let far = reversed.map_depth(0.5);
+        let near = reversed.map_depth(-0.5);
+        assert_eq!((near, far), (0.75, 0.25));
+        assert!(gpu.z_buffer.test_and_set(1, 1, far, &reversed));
+        assert!(gpu.z_buffer.test_and_set(1, 1, near, &reversed));
+        assert!(!gpu.z_buffer.test_and_set(1, 1, far, &reversed));
+        assert_eq!(gpu.z_buffer.get(1, 1), near);
+
+        // Skyboxes pushed to the far plane only pass where nothing was drawn
+        let skybox = DepthState {
+            compare: DepthCompare::LessEqual,
+            range: (1.0, 1.0),
+            clear_value: 1.0,
+            ..default
+        };
+        gpu.clear_z_buffer(&skybox);
+        assert!(gpu
+            .z_buffer
+            .test_and_set(0, 0, default.map_depth(0.0), &skybox));
+        assert!(!gpu
+            .z_buffer
+            .test_and_set(0, 0, skybox.map_depth(-1.0), &skybox));
+        assert!(gpu
+            .z_buffer
+            .test_and_set(1, 0, skybox.map_depth(-1.0), &skybox));
+    }
+
+    #[test]
+    fn resizing_keeps_the_projection_in_step() {
+        let mut gpu = Gpu::new(128, 72);
+        let mut frame_uniforms = FrameUniforms::new(128, 72);
+        frame_uniforms.view = Transform3::identity() * Translation3::new(1.0, 2.0, 3.0);
+        let view = frame_uniforms.view;
+
+        gpu.resize(48, 20, &mut frame_uniforms);
+        assert_eq!(gpu.size(), (48, 20));
+        assert_eq!(frame_uniforms.projection.aspect(), 48.0 / 20.0);
+        assert_eq!(frame_uniforms.view, view);
+    }
+}
@@ -1,15 +1,115 @@
+use nalgebra::SVector;
+
 use crate::{
-    gpu::ZBuffer,
-    shaders::pixel_shader::PixelShader,
-    types::{Triangle, TriangleRef, TriangleVertex},
+    blend::BlendMode,
+    gpu::RenderTarget,
+    shaders::pixel_shader::{Derivatives, PixelShader},
+    types::{Line, Triangle, TriangleRef, TriangleVertex},
 };
 
-use gamercade_rs::prelude as gc;
+// Selects the algorithm used to fill triangles
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum Rasterizer {
+    // Splits triangles into flat top and flat bottom halves
+    // and walks their edges one scanline at a time
+    #[default]
+    Scanline,
+    // Tests each pixel of the bounding box against the triangle's
+    // edge functions, in fixed point and with a top-left fill rule
+    EdgeFunction,
+}
+
+impl Rasterizer {
+    pub fn draw<PS: PixelShader<D> + ?Sized, const D: usize>(
+        self,
+        triangle: &Triangle<D>,
+        shader: &PS,
+        target: &mut RenderTarget,
+    ) {
+        match self {
+            Self::Scanline => draw_triangle(triangle, shader, target),
+            Self::EdgeFunction => draw_triangle_edge_function(triangle, shader, target),
+        }
+    }
+}
+
+// How shader parameters are interpolated across triangles.
+//
+// Screen space positions are linear in screen space, but attributes are
+// only linear in clip space. When converting to screen space every
+// parameter is divided by w, and 1/w is stored in position.w. Those values
+// are linear in screen space, so the rasterizers step them linearly, and
+// each pixel multiplies its parameters by the interpolated w to undo the
+// divide. Depth is z/w, which is also linear and is used as is.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum Interpolation {
+    // Perspective correct, as above
+    #[default]
+    Perspective,
+    // Parameters are interpolated linearly in screen space, which warps
+    // textures on surfaces at an angle to the camera like the PS1 did.
+    // position.w is left as 1 so shading doesn't undo anything
+    Affine,
+}
+
+// How triangles are drawn
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum PolygonMode {
+    #[default]
+    Fill,
+    // Only the edges of each triangle are drawn, as lines
+    Line,
+}
+
+// Precision of the edge function rasterizer, vertices are snapped to 1/16th of a pixel
+const SUB_PIXEL_BITS: i64 = 4;
+const SUB_PIXEL_SCALE: f32 = (1 << SUB_PIXEL_BITS) as f32;
+const PIXEL_SIZE: i64 = 1 << SUB_PIXEL_BITS;
+const HALF_PIXEL: i64 = PIXEL_SIZE / 2;
+
+// How the values stepped across a triangle change for each pixel to the
+// right and down. They are linear in screen space, so this is the same
+// over the whole triangle.
+struct Gradients<const D: usize> {
+    dx: TriangleVertex<D>,
+    dy: TriangleVertex<D>,
+}
+
+impl<const D: usize> Gradients<D> {
+    fn new([a, b, c]: [&TriangleVertex<D>; 3]) -> Self {
+        let (ab, ac) = (b - a, c - a);
+        let inverse_area = (ab.position.x * ac.position.y - ac.position.x * ab.position.y).recip();
+
+        let mut dx = &ab * (ac.position.y * inverse_area);
+        dx += &ac * (-ab.position.y * inverse_area);
+        let mut dy = &ac * (ab.position.x * inverse_area);
+        dy += &ab * (-ac.position.x * inverse_area);
+
+        Self { dx, dy }
+    }
+
+    // Perspective correct derivatives of the parameters, from the quotient
+    // rule on the parameters divided by w and 1/w, see Interpolation
+    fn derivatives(&self, params: &SVector<f32, D>, vertex: &TriangleVertex<D>) -> Derivatives<D> {
+        let w = vertex.position.w.recip();
+        let derivative =
+            |gradient: &TriangleVertex<D>| (gradient.parameters - params * gradient.position.w) * w;
+
+        Derivatives {
+            dx: derivative(&self.dx),
+            dy: derivative(&self.dy),
+        }
+    }
+}
 
-pub fn draw_triangle<PS: PixelShader<D>, const D: usize>(
-    mut triangle: Triangle<D>,
-    z_buffer: &mut ZBuffer,
+pub fn draw_triangle<PS: PixelShader<D> + ?Sized, const D: usize>(
+    triangle: &Triangle<D>,
+    shader: &PS,
+    target: &mut RenderTarget,
 ) {
+    let mut triangle = triangle.to_ref();
+    let gradients = Gradients::new(triangle.vertices);
+
     // Sort verts from top (low) to bottom (high)
     triangle
         .vertices
@@ -21,63 +121,66 @@ pub fn draw_triangle<PS: PixelShader<D>, const D: usize>(
         if triangle.vertices[0].position.x > triangle.vertices[1].position.x {
             triangle.vertices.swap(0, 1);
         }
-        draw_flat_top_triangle::<PS, D>(triangle.to_ref(), z_buffer);
+        draw_flat_top_triangle(triangle, &gradients, shader, target);
     } else if triangle.vertices[1].position.y == triangle.vertices[2].position.y {
         // Flat Bottom
         // We want to go left -> right
         if triangle.vertices[1].position.x > triangle.vertices[2].position.x {
             triangle.vertices.swap(1, 2);
         }
-        draw_flat_bottom_triangle::<PS, D>(triangle.to_ref(), z_buffer);
+        draw_flat_bottom_triangle(triangle, &gradients, shader, target);
     } else {
         // Split the triangle into a flat top and flat bottom triangle
         let alpha = (triangle.vertices[1].position.y - triangle.vertices[0].position.y)
             / (triangle.vertices[2].position.y - triangle.vertices[0].position.y);
 
-        let split = TriangleVertex {
-            position: triangle.vertices[0]
-                .position
-                .lerp(&triangle.vertices[2].position, alpha),
-            parameters: triangle.vertices[0]
-                .parameters
-                .lerp(&triangle.vertices[2].parameters, alpha),
-        };
+        let split = triangle.vertices[0].lerp(triangle.vertices[2], alpha);
 
         if split.position.x > triangle.vertices[1].position.x {
             // Split is on the right side
-            draw_flat_bottom_triangle::<PS, D>(
+            draw_flat_bottom_triangle(
                 TriangleRef {
-                    vertices: [&triangle.vertices[0], &triangle.vertices[1], &split],
+                    vertices: [triangle.vertices[0], triangle.vertices[1], &split],
                 },
-                z_buffer,
+                &gradients,
+                shader,
+                target,
             );
-            draw_flat_top_triangle::<PS, D>(
+            draw_flat_top_triangle(
                 TriangleRef {
-                    vertices: [&triangle.vertices[1], &split, &triangle.vertices[2]],
+                    vertices: [triangle.vertices[1], &split, triangle.vertices[2]],
                 },
-                z_buffer,
+                &gradients,
+                shader,
+                target,
             );
         } else {
             // Split is on the left side
-            draw_flat_bottom_triangle::<PS, D>(
+            draw_flat_bottom_triangle(
                 TriangleRef {
-                    vertices: [&triangle.vertices[0], &split, &triangle.vertices[1]],
+                    vertices: [triangle.vertices[0], &split, triangle.vertices[1]],
                 },
-                z_buffer,
+                &gradients,
+                shader,
+                target,
             );
-            draw_flat_top_triangle::<PS, D>(
+            draw_flat_top_triangle(
                 TriangleRef {
-                    vertices: [&split, &triangle.vertices[1], &triangle.vertices[2]],
+                    vertices: [&split, triangle.vertices[1], triangle.vertices[2]],
                 },
-                z_buffer,
+                &gradients,
+                shader,
+                target,
             );
         }
     }
 }
 
-fn draw_flat_top_triangle<PS: PixelShader<D>, const D: usize>(
+fn draw_flat_top_triangle<PS: PixelShader<D> + ?Sized, const D: usize>(
     triangle: TriangleRef<D>,
-    z_buffer: &mut ZBuffer,
+    gradients: &Gradients<D>,
+    shader: &PS,
+    target: &mut RenderTarget,
 ) {
     let verts = triangle.vertices;
     let delta_y = verts[2].position.y - verts[0].position.y;
@@ -86,12 +189,22 @@ fn draw_flat_top_triangle<PS: PixelShader<D>, const D: usize>(
 
     let edge_interpolator = verts[1].clone();
 
-    draw_flat_triangle::<PS, D>(triangle, &dit0, &dit1, edge_interpolator, z_buffer);
+    draw_flat_triangle(
+        triangle,
+        &dit0,
+        &dit1,
+        edge_interpolator,
+        gradients,
+        shader,
+        target,
+    );
 }
 
-fn draw_flat_bottom_triangle<PS: PixelShader<D>, const D: usize>(
+fn draw_flat_bottom_triangle<PS: PixelShader<D> + ?Sized, const D: usize>(
     triangle: TriangleRef<D>,
-    z_buffer: &mut ZBuffer,
+    gradients: &Gradients<D>,
+    shader: &PS,
+    target: &mut RenderTarget,
 ) {
     let verts = triangle.vertices;
     let delta_y = verts[2].position.y - verts[0].position.y;
@@ -100,29 +213,41 @@ fn draw_flat_bottom_triangle<PS: PixelShader<D>, const D: usize>(
 
     let edge_interpolator = verts[0].clone();
 
-    draw_flat_triangle::<PS, D>(triangle, &dit0, &dit1, edge_interpolator, z_buffer);
+    draw_flat_triangle(
+        triangle,
+        &dit0,
+        &dit1,
+        edge_interpolator,
+        gradients,
+        shader,
+        target,
+    );
 }
 
-fn draw_flat_triangle<PS: PixelShader<D>, const D: usize>(
+fn draw_flat_triangle<PS: PixelShader<D> + ?Sized, const D: usize>(
     triangle: TriangleRef<D>,
     dv0: &TriangleVertex<D>,
     dv1: &TriangleVertex<D>,
     mut interpolator_edge_1: TriangleVertex<D>,
-    z_buffer: &mut ZBuffer,
+    gradients: &Gradients<D>,
+    shader: &PS,
+    target: &mut RenderTarget,
 ) {
     let mut interpolator_edge_0 = triangle.vertices[0].clone();
 
-    let y_start = ((triangle.vertices[0].position.y - 0.5).ceil() as i32).max(0);
-    let y_end = ((triangle.vertices[2].position.y - 0.5).ceil() as i32)
-        .min(z_buffer.screen_height as i32 - 1);
+    // Pixel centers are sampled, and end values are exclusive.
+    // The guard band lets triangles extend past the screen,
+    // so trim them to the edges of the depth buffer here.
+    let bounds = target.z_buffer.bounds();
+    let y_start = ((triangle.vertices[0].position.y - 0.5).ceil() as i32).max(bounds.y_start);
+    let y_end = ((triangle.vertices[2].position.y - 0.5).ceil() as i32).min(bounds.y_end);
 
     interpolator_edge_0 += dv0 * (y_start as f32 + 0.5 - triangle.vertices[0].position.y);
     interpolator_edge_1 += dv1 * (y_start as f32 + 0.5 - triangle.vertices[0].position.y);
 
     for y in y_start..y_end {
-        let x_start = ((interpolator_edge_0.position.x - 0.5).ceil() as i32).max(0);
-        let x_end = ((interpolator_edge_1.position.x - 0.5).ceil() as i32)
-            .min(z_buffer.screen_width as i32 - 1);
+        let x_start = ((interpolator_edge_0.position.x - 0.5).ceil() as i32).max(bounds.x_start);
+        let x_end = ((interpolator_edge_1.position.x - 0.5).ceil() as i32).min(bounds.x_end);
 
         let mut interpolation_line = interpolator_edge_0.clone();
         let dx = interpolator_edge_1.position.x - interpolator_edge_0.position.x;
@@ -132,13 +257,7 @@ fn draw_flat_triangle<PS: PixelShader<D>, const D: usize>(
             &delta_interpolation_line * (x_start as f32 + 0.5 - interpolator_edge_0.position.x);
 
         for x in x_start..x_end {
-            if z_buffer.test_and_set(x as usize, y as usize, interpolation_line.position.z) {
-                let w = interpolation_line.position.w.recip();
-                let params = interpolation_line.parameters * w;
-                let color = PS::run(params).to_graphics_params();
-                gc::set_pixel(color, x, y);
-            }
-
+            shade_pixel(x, y, &interpolation_line, Some(gradients), shader, target);
             interpolation_line += &delta_interpolation_line;
         }
 
@@ -146,3 +265,192 @@ fn draw_flat_triangle<PS: PixelShader<D>, const D: usize>(
         interpolator_edge_1 += dv1;
     }
 }
+
+// An edge of a triangle, in sub pixel fixed point
+struct Edge {
+    // Value of the edge function at the first pixel center
+    value: i64,
+    // Change in value when moving one pixel right or down
+    step_x: i64,
+    step_y: i64,
+    // Pixels exactly on the edge are only drawn for top and left edges,
+    // this is subtracted from the value to push the others out
+    bias: i64,
+}
+
+impl Edge {
+    fn new(from: (i64, i64), to: (i64, i64), origin: (i64, i64)) -> Self {
+        let dx = to.0 - from.0;
+        let dy = to.1 - from.1;
+
+        // Triangles are wound so that the inside is on the positive side
+        // With y pointing down, top edges go right and left edges go up
+        let is_top_left = dy < 0 || (dy == 0 && dx > 0);
+
+        Self {
+            value: dx * (origin.1 - from.1) - dy * (origin.0 - from.0),
+            step_x: -dy * PIXEL_SIZE,
+            step_y: dx * PIXEL_SIZE,
+            bias: if is_top_left { 0 } else { 1 },
+        }
+    }
+}
+
+pub fn draw_triangle_edge_function<PS: PixelShader<D> + ?Sized, const D: usize>(
+    triangle: &Triangle<D>,
+    shader: &PS,
+    target: &mut RenderTarget,
+) {
+    let [a, mut b, mut c] = triangle.vertices.each_ref().map(|vertex| {
+        (
+            (vertex.position.x * SUB_PIXEL_SCALE).round() as i64,
+            (vertex.position.y * SUB_PIXEL_SCALE).round() as i64,
+        )
+    });
+    let [va, mut vb, mut vc] = triangle.vertices.each_ref();
+
+    // Twice the area of the triangle, negative when wound the other way
+    let mut area = (b.0 - a.0) * (c.1 - a.1) - (b.1 - a.1) * (c.0 - a.0);
+    if area == 0 {
+        return;
+    } else if area < 0 {
+        std::mem::swap(&mut b, &mut c);
+        std::mem::swap(&mut vb, &mut vc);
+        area = -area;
+    }
+
+    // Bounding box in whole pixels, trimmed to the depth buffer
+    let pixel_start = |min: i64| (min - HALF_PIXEL + PIXEL_SIZE - 1).div_euclid(PIXEL_SIZE);
+    let pixel_end = |max: i64| (max - HALF_PIXEL).div_euclid(PIXEL_SIZE) + 1;
+
+    let bounds = target.z_buffer.bounds();
+    let x_start = pixel_start(a.0.min(b.0).min(c.0)).max(bounds.x_start as i64);
+    let x_end = pixel_end(a.0.max(b.0).max(c.0)).min(bounds.x_end as i64);
+    let y_start = pixel_start(a.1.min(b.1).min(c.1)).max(bounds.y_start as i64);
+    let y_end = pixel_end(a.1.max(b.1).max(c.1)).min(bounds.y_end as i64);
+
+    if x_start >= x_end || y_start >= y_end {
+        return;
+    }
+
+    let origin = (
+        x_start * PIXEL_SIZE + HALF_PIXEL,
+        y_start * PIXEL_SIZE + HALF_PIXEL,
+    );
+
+    // Each edge function is the barycentric weight of the opposite vertex
+    let mut edges = [
+        Edge::new(b, c, origin),
+        Edge::new(c, a, origin),
+        Edge::new(a, b, origin),
+    ];
+
+    // The attributes are linear in screen space, so work out
+    // how much they change per pixel once, and step them along
+    let inverse_area = (area as f32).recip();
+    let weighted = |weights: [f32; 3]| {
+        let mut out = va * (weights[0] * inverse_area);
+        out += vb * (weights[1] * inverse_area);
+        out += vc * (weights[2] * inverse_area);
+        out
+    };
+
+    let gradients = Gradients {
+        dx: weighted(edges.each_ref().map(|edge| edge.step_x as f32)),
+        dy: weighted(edges.each_ref().map(|edge| edge.step_y as f32)),
+    };
+    let mut row_interpolator = weighted(edges.each_ref().map(|edge| edge.value as f32));
+
+    for y in y_start..y_end {
+        let mut values = edges.each_ref().map(|edge| edge.value - edge.bias);
+        let mut interpolator = row_interpolator.clone();
+
+        for x in x_start..x_end {
+            if values.iter().all(|value| *value >= 0) {
+                shade_pixel(
+                    x as i32,
+                    y as i32,
+                    &interpolator,
+                    Some(&gradients),
+                    shader,
+                    target,
+                );
+            }
+
+            values
+                .iter_mut()
+                .zip(edges.iter())
+                .for_each(|(value, edge)| *value += edge.step_x);
+            interpolator += &gradients.dx;
+        }
+
+        edges.iter_mut().for_each(|edge| edge.value += edge.step_y);
+        row_interpolator += &gradients.dy;
+    }
+}
+
+// Steps along the line one pixel at a time on its longer axis, so
+// there are no gaps. Parameters and depth are interpolated the same
+// way as for triangles, and every pixel is depth tested.
+pub fn draw_line<PS: PixelShader<D> + ?Sized, const D: usize>(
+    line: &Line<D>,
+    shader: &PS,
+    target: &mut RenderTarget,
+) {
+    let [start, end] = &line.vertices;
+    let bounds = target.z_buffer.bounds();
+
+    let dx = end.position.x - start.position.x;
+    let dy = end.position.y - start.position.y;
+    let steps = dx.abs().max(dy.abs()).ceil().max(1.0);
+
+    let step = (end - start) / steps;
+    let mut vertex = start.clone();
+
+    for _ in 0..=steps as usize {
+        let x = vertex.position.x.floor() as i32;
+        let y = vertex.position.y.floor() as i32;
+
+        if bounds.contains(x, y) {
+            shade_pixel(x, y, &vertex, None, shader, target);
+        }
+
+        vertex += &step;
+    }
+}
+
+// Depth tests a single pixel, and runs the pixel shader if it passes.
+// The parameters are still divided by w, see Interpolation. Lines
+// have no gradients, so their shaders don't get any derivatives,
+// and neither do shaders which don't use them.
+fn shade_pixel<PS: PixelShader<D> + ?Sized, const D: usize>(
+    x: i32,
+    y: i32,
+    vertex: &TriangleVertex<D>,
+    gradients: Option<&Gradients<D>>,
+    shader: &PS,
+    target: &mut RenderTarget,
+) {
+    let (x, y) = (x as usize, y as usize);
+    let depth = target.depth_state.map_depth(vertex.position.z);
+
+    if target
+        .z_buffer
+        .test_and_set(x, y, depth, &target.depth_state)
+    {
+        let w = vertex.position.w.recip();
+        let params = vertex.parameters * w;
+        let color = match gradients.filter(|_| shader.uses_derivatives()) {
+            Some(gradients) => {
+                shader.run_with_derivatives(params, &gradients.derivatives(&params, vertex))
+            }
+            None => shader.run(params),
+        };
+
+        let color = match target.blend_mode {
+            BlendMode::Opaque => color,
+            blend_mode => blend_mode.blend(color, target.color_buffer.get(x, y)),
+        };
+        target.color_buffer.set(x, y, color);
+    }
+}
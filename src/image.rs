@@ -1,4136 +1,6 @@
-use gamercade_rs::prelude::console_log;
+use crate::{assets, texture::Texture};
 
-use crate::types::Color;
-
-pub const IMAGE_WIDTH: usize = 64;
-pub const IMAGE_HEIGHT: usize = 64;
-pub const IMAGE_PIXEL_COUNT: usize = IMAGE_WIDTH * IMAGE_HEIGHT;
-
-static mut IMAGE: Option<[Color; IMAGE_PIXEL_COUNT]> = None;
-
-pub fn get_image() -> &'static [Color; IMAGE_PIXEL_COUNT] {
-    unsafe {
-        IMAGE.get_or_insert_with(|| {
-            match DATA
-                .iter()
-                .map(|color| {
-                    let r = u8::from_str_radix(color[0], 16).unwrap();
-                    let g = u8::from_str_radix(color[1], 16).unwrap();
-                    let b = u8::from_str_radix(color[2], 16).unwrap();
-
-                    Color { r, g, b }
-                })
-                .collect::<Vec<_>>()
-                .try_into()
-            {
-                Ok(v) => {
-                    console_log("texture generated");
-                    v
-                }
-                Err(_) => {
-                    console_log("failed to generate image");
-                    panic!();
-                }
-            }
-        })
-    }
+// The texture on the demo's cubes
+pub fn texture() -> Texture {
+    Texture::from_packed(assets::IMAGE)
 }
-
-const DATA: &[[&str; 3]; IMAGE_PIXEL_COUNT] = &[
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["27", "AB", "FE"],
-    ["27", "AB", "FE"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["27", "AB", "FE"],
-    ["27", "AA", "FE"],
-    ["FF", "FF", "FF"],
-    ["52", "B6", "FE"],
-    ["52", "B5", "FE"],
-    ["52", "B4", "FE"],
-    ["51", "B1", "FE"],
-    ["51", "B1", "FE"],
-    ["51", "AE", "FE"],
-    ["26", "98", "FE"],
-    ["26", "96", "FE"],
-    ["26", "94", "FE"],
-    ["26", "92", "FE"],
-    ["26", "91", "FE"],
-    ["26", "8D", "FE"],
-    ["26", "8B", "FE"],
-    ["26", "89", "FE"],
-    ["26", "87", "FE"],
-    ["25", "85", "FE"],
-    ["25", "84", "FE"],
-    ["25", "80", "FE"],
-    ["25", "7F", "FE"],
-    ["25", "7D", "FE"],
-    ["25", "7B", "FE"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["27", "AA", "FE"],
-    ["27", "A9", "FE"],
-    ["27", "A7", "FE"],
-    ["27", "A6", "FE"],
-    ["FF", "FF", "FF"],
-    ["52", "B3", "FE"],
-    ["51", "B1", "FE"],
-    ["51", "B1", "FE"],
-    ["51", "AE", "FE"],
-    ["51", "AD", "FE"],
-    ["26", "96", "FE"],
-    ["26", "94", "FE"],
-    ["26", "92", "FE"],
-    ["26", "90", "FE"],
-    ["26", "8D", "FE"],
-    ["26", "8D", "FE"],
-    ["26", "89", "FE"],
-    ["26", "87", "FE"],
-    ["25", "85", "FE"],
-    ["25", "83", "FE"],
-    ["25", "81", "FE"],
-    ["25", "80", "FE"],
-    ["25", "7C", "FE"],
-    ["25", "7B", "FE"],
-    ["25", "79", "FE"],
-    ["25", "77", "FE"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["27", "A8", "FE"],
-    ["27", "A7", "FE"],
-    ["27", "A5", "FE"],
-    ["27", "A4", "FE"],
-    ["FF", "FF", "FF"],
-    ["51", "B1", "FE"],
-    ["51", "B0", "FE"],
-    ["51", "AF", "FE"],
-    ["51", "AD", "FE"],
-    ["51", "AC", "FE"],
-    ["26", "94", "FE"],
-    ["26", "92", "FE"],
-    ["26", "90", "FE"],
-    ["26", "8E", "FE"],
-    ["26", "8B", "FE"],
-    ["26", "8B", "FE"],
-    ["26", "87", "FE"],
-    ["25", "85", "FE"],
-    ["25", "83", "FE"],
-    ["25", "81", "FE"],
-    ["25", "7F", "FE"],
-    ["25", "7E", "FE"],
-    ["25", "7A", "FE"],
-    ["25", "79", "FE"],
-    ["25", "77", "FE"],
-    ["25", "75", "FE"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["27", "A8", "FE"],
-    ["27", "A6", "FE"],
-    ["27", "A2", "FE"],
-    ["27", "A1", "FE"],
-    ["27", "9F", "FE"],
-    ["26", "9E", "FE"],
-    ["FF", "FF", "FF"],
-    ["51", "AD", "FE"],
-    ["51", "AB", "FE"],
-    ["51", "AA", "FE"],
-    ["26", "92", "FE"],
-    ["26", "91", "FE"],
-    ["26", "8D", "FE"],
-    ["26", "8C", "FE"],
-    ["26", "89", "FE"],
-    ["26", "88", "FE"],
-    ["25", "85", "FE"],
-    ["25", "84", "FE"],
-    ["25", "81", "FE"],
-    ["25", "7F", "FE"],
-    ["25", "7D", "FE"],
-    ["25", "7B", "FE"],
-    ["25", "79", "FE"],
-    ["25", "78", "FE"],
-    ["25", "74", "FE"],
-    ["25", "73", "FE"],
-    ["25", "70", "FE"],
-    ["25", "6F", "FE"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["27", "A6", "FE"],
-    ["27", "A4", "FE"],
-    ["27", "A0", "FE"],
-    ["27", "9F", "FE"],
-    ["26", "9D", "FE"],
-    ["26", "9C", "FE"],
-    ["FF", "FF", "FF"],
-    ["51", "AB", "FE"],
-    ["51", "A9", "FE"],
-    ["26", "93", "FE"],
-    ["26", "90", "FE"],
-    ["26", "8F", "FE"],
-    ["26", "8B", "FE"],
-    ["26", "8A", "FE"],
-    ["26", "87", "FE"],
-    ["25", "86", "FE"],
-    ["25", "83", "FE"],
-    ["25", "82", "FE"],
-    ["25", "7F", "FE"],
-    ["25", "7D", "FE"],
-    ["25", "7B", "FE"],
-    ["25", "79", "FE"],
-    ["25", "77", "FE"],
-    ["25", "76", "FE"],
-    ["25", "72", "FE"],
-    ["25", "71", "FE"],
-    ["25", "6E", "FE"],
-    ["24", "6D", "FE"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["27", "A5", "FE"],
-    ["27", "A4", "FE"],
-    ["27", "A2", "FE"],
-    ["27", "A0", "FE"],
-    ["26", "9C", "FE"],
-    ["26", "9B", "FE"],
-    ["26", "99", "FE"],
-    ["26", "98", "FE"],
-    ["FF", "FF", "FF"],
-    ["51", "A8", "FE"],
-    ["26", "90", "FE"],
-    ["26", "8F", "FE"],
-    ["26", "8C", "FE"],
-    ["26", "8B", "FE"],
-    ["26", "87", "FE"],
-    ["25", "86", "FE"],
-    ["25", "83", "FE"],
-    ["25", "82", "FE"],
-    ["25", "7F", "FE"],
-    ["25", "7E", "FE"],
-    ["25", "7B", "FE"],
-    ["25", "79", "FE"],
-    ["25", "77", "FE"],
-    ["25", "75", "FE"],
-    ["25", "73", "FE"],
-    ["25", "72", "FE"],
-    ["25", "6E", "FE"],
-    ["24", "6D", "FE"],
-    ["24", "6A", "FE"],
-    ["24", "69", "FE"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["26", "90", "FE"],
-    ["26", "8D", "FE"],
-    ["26", "8D", "FE"],
-    ["26", "8A", "FE"],
-    ["26", "89", "FE"],
-    ["25", "85", "FE"],
-    ["25", "84", "FE"],
-    ["25", "81", "FE"],
-    ["25", "80", "FE"],
-    ["25", "7D", "FE"],
-    ["25", "7C", "FE"],
-    ["25", "79", "FE"],
-    ["25", "77", "FE"],
-    ["25", "75", "FE"],
-    ["25", "73", "FE"],
-    ["25", "71", "FE"],
-    ["25", "70", "FE"],
-    ["24", "6C", "FE"],
-    ["24", "6B", "FE"],
-    ["24", "68", "FE"],
-    ["24", "67", "FE"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["20", "7F", "D3"],
-    ["20", "7E", "D3"],
-    ["20", "7B", "D3"],
-    ["20", "7A", "D3"],
-    ["20", "77", "D3"],
-    ["20", "77", "D3"],
-    ["20", "75", "D3"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["25", "81", "FE"],
-    ["25", "7F", "FE"],
-    ["25", "7D", "FE"],
-    ["25", "7C", "FE"],
-    ["25", "79", "FE"],
-    ["25", "78", "FE"],
-    ["25", "75", "FE"],
-    ["25", "73", "FE"],
-    ["25", "71", "FE"],
-    ["25", "6F", "FE"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["26", "9A", "FE"],
-    ["20", "7C", "D3"],
-    ["20", "79", "D3"],
-    ["20", "78", "D3"],
-    ["20", "76", "D3"],
-    ["20", "76", "D3"],
-    ["20", "73", "D3"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["25", "7F", "FE"],
-    ["25", "7D", "FE"],
-    ["25", "7B", "FE"],
-    ["25", "7A", "FE"],
-    ["25", "77", "FE"],
-    ["25", "76", "FE"],
-    ["25", "73", "FE"],
-    ["25", "71", "FE"],
-    ["25", "6F", "FE"],
-    ["24", "6D", "FE"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["26", "94", "FE"],
-    ["26", "92", "FE"],
-    ["20", "74", "D3"],
-    ["20", "73", "D3"],
-    ["20", "71", "D3"],
-    ["20", "70", "D3"],
-    ["20", "6F", "D3"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["26", "92", "FE"],
-    ["26", "90", "FE"],
-    ["26", "8C", "FE"],
-    ["20", "72", "D3"],
-    ["20", "6F", "D3"],
-    ["20", "6F", "D3"],
-    ["20", "6D", "D3"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["26", "8D", "FE"],
-    ["26", "8C", "FE"],
-    ["26", "88", "FE"],
-    ["26", "87", "FE"],
-    ["20", "6C", "D3"],
-    ["20", "6B", "D3"],
-    ["20", "6A", "D3"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["26", "8B", "FE"],
-    ["26", "8A", "FE"],
-    ["25", "86", "FE"],
-    ["25", "85", "FE"],
-    ["25", "82", "FE"],
-    ["20", "6A", "D3"],
-    ["20", "68", "D3"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["26", "87", "FE"],
-    ["25", "86", "FE"],
-    ["25", "82", "FE"],
-    ["25", "81", "FE"],
-    ["25", "7E", "FE"],
-    ["25", "7D", "FE"],
-    ["20", "65", "D3"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["25", "72", "FE"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["25", "83", "FE"],
-    ["25", "81", "FE"],
-    ["25", "7E", "FE"],
-    ["25", "7D", "FE"],
-    ["25", "7A", "FE"],
-    ["25", "79", "FE"],
-    ["25", "77", "FE"],
-    ["FF", "FF", "FF"],
-    ["25", "71", "FE"],
-    ["25", "70", "FE"],
-    ["25", "6E", "FE"],
-    ["24", "6D", "FE"],
-    ["FF", "FF", "FF"],
-    ["24", "67", "FE"],
-    ["24", "65", "FE"],
-    ["24", "64", "FE"],
-    ["24", "61", "FE"],
-    ["24", "60", "FE"],
-    ["24", "5C", "FE"],
-    ["24", "5B", "FE"],
-    ["24", "59", "FE"],
-    ["50", "79", "FE"],
-    ["4F", "76", "FF"],
-    ["4F", "75", "FF"],
-    ["4F", "73", "FF"],
-    ["4F", "71", "FF"],
-    ["4F", "70", "FF"],
-    ["4F", "6E", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["25", "7F", "FE"],
-    ["25", "7D", "FE"],
-    ["25", "7A", "FE"],
-    ["25", "79", "FE"],
-    ["25", "76", "FE"],
-    ["25", "75", "FE"],
-    ["25", "73", "FE"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["24", "63", "FE"],
-    ["24", "61", "FE"],
-    ["24", "60", "FE"],
-    ["24", "5D", "FE"],
-    ["24", "5C", "FE"],
-    ["24", "58", "FE"],
-    ["24", "57", "FE"],
-    ["23", "54", "FF"],
-    ["23", "53", "FF"],
-    ["4F", "73", "FF"],
-    ["4F", "72", "FF"],
-    ["4F", "70", "FF"],
-    ["4F", "6E", "FF"],
-    ["4F", "6D", "FF"],
-    ["4F", "6B", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["25", "7D", "FE"],
-    ["25", "7B", "FE"],
-    ["25", "78", "FE"],
-    ["25", "77", "FE"],
-    ["25", "74", "FE"],
-    ["25", "73", "FE"],
-    ["25", "71", "FE"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["24", "61", "FE"],
-    ["24", "5F", "FE"],
-    ["24", "5E", "FE"],
-    ["24", "5B", "FE"],
-    ["24", "5A", "FE"],
-    ["24", "56", "FE"],
-    ["23", "55", "FF"],
-    ["23", "52", "FF"],
-    ["23", "51", "FF"],
-    ["23", "4E", "FF"],
-    ["4F", "71", "FF"],
-    ["4F", "6E", "FF"],
-    ["4F", "6D", "FF"],
-    ["4F", "6B", "FF"],
-    ["4F", "69", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["25", "79", "FE"],
-    ["25", "77", "FE"],
-    ["25", "74", "FE"],
-    ["25", "73", "FE"],
-    ["25", "70", "FE"],
-    ["25", "6F", "FE"],
-    ["24", "6D", "FE"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["24", "5D", "FE"],
-    ["24", "5B", "FE"],
-    ["24", "5A", "FE"],
-    ["24", "57", "FE"],
-    ["24", "56", "FE"],
-    ["23", "52", "FF"],
-    ["23", "51", "FF"],
-    ["23", "4E", "FF"],
-    ["23", "4D", "FF"],
-    ["23", "4A", "FF"],
-    ["23", "49", "FF"],
-    ["4F", "6B", "FF"],
-    ["4F", "69", "FF"],
-    ["4F", "68", "FF"],
-    ["4F", "66", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["25", "77", "FE"],
-    ["25", "75", "FE"],
-    ["25", "72", "FE"],
-    ["25", "71", "FE"],
-    ["25", "6E", "FE"],
-    ["24", "6D", "FE"],
-    ["24", "6B", "FE"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["23", "4C", "FF"],
-    ["23", "4B", "FF"],
-    ["23", "48", "FF"],
-    ["23", "47", "FF"],
-    ["4F", "69", "FF"],
-    ["4F", "68", "FF"],
-    ["4F", "66", "FF"],
-    ["4F", "65", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["25", "71", "FE"],
-    ["25", "6F", "FE"],
-    ["24", "6C", "FE"],
-    ["24", "6B", "FE"],
-    ["24", "68", "FE"],
-    ["24", "67", "FE"],
-    ["24", "65", "FE"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["23", "46", "FF"],
-    ["23", "45", "FF"],
-    ["23", "42", "FF"],
-    ["23", "41", "FF"],
-    ["23", "3E", "FF"],
-    ["22", "3C", "FF"],
-    ["4E", "61", "FF"],
-    ["4E", "60", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["25", "6F", "FE"],
-    ["24", "6D", "FE"],
-    ["24", "6A", "FE"],
-    ["24", "69", "FE"],
-    ["24", "66", "FE"],
-    ["24", "65", "FE"],
-    ["24", "63", "FE"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["23", "54", "FF"],
-    ["23", "53", "FF"],
-    ["23", "51", "FF"],
-    ["23", "50", "FF"],
-    ["23", "4D", "FF"],
-    ["23", "4C", "FF"],
-    ["23", "48", "FF"],
-    ["23", "47", "FF"],
-    ["23", "44", "FF"],
-    ["23", "43", "FF"],
-    ["23", "40", "FF"],
-    ["23", "3F", "FF"],
-    ["22", "3C", "FF"],
-    ["22", "3A", "FF"],
-    ["22", "38", "FF"],
-    ["4E", "5E", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["24", "5C", "FE"],
-    ["24", "59", "FE"],
-    ["24", "58", "FE"],
-    ["24", "56", "FE"],
-    ["23", "54", "FF"],
-    ["23", "50", "FF"],
-    ["23", "4F", "FF"],
-    ["23", "4D", "FF"],
-    ["23", "4C", "FF"],
-    ["23", "49", "FF"],
-    ["23", "48", "FF"],
-    ["23", "44", "FF"],
-    ["23", "43", "FF"],
-    ["23", "40", "FF"],
-    ["23", "3F", "FF"],
-    ["22", "3C", "FF"],
-    ["22", "3B", "FF"],
-    ["22", "38", "FF"],
-    ["22", "36", "FF"],
-    ["22", "34", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["24", "6D", "FE"],
-    ["24", "6B", "FE"],
-    ["24", "69", "FE"],
-    ["24", "67", "FE"],
-    ["24", "64", "FE"],
-    ["24", "63", "FE"],
-    ["24", "60", "FE"],
-    ["24", "5F", "FE"],
-    ["FF", "FF", "FF"],
-    ["1F", "4B", "D3"],
-    ["24", "57", "FE"],
-    ["24", "56", "FE"],
-    ["23", "54", "FF"],
-    ["23", "52", "FF"],
-    ["23", "4E", "FF"],
-    ["23", "4D", "FF"],
-    ["23", "4B", "FF"],
-    ["23", "4A", "FF"],
-    ["23", "47", "FF"],
-    ["23", "46", "FF"],
-    ["23", "42", "FF"],
-    ["23", "41", "FF"],
-    ["23", "3E", "FF"],
-    ["22", "3D", "FF"],
-    ["22", "3A", "FF"],
-    ["22", "39", "FF"],
-    ["22", "36", "FF"],
-    ["22", "34", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["24", "65", "FE"],
-    ["24", "63", "FE"],
-    ["24", "60", "FE"],
-    ["24", "5F", "FE"],
-    ["24", "5C", "FE"],
-    ["24", "5B", "FE"],
-    ["FF", "FF", "FF"],
-    ["1F", "47", "D3"],
-    ["1E", "45", "D4"],
-    ["23", "52", "FF"],
-    ["23", "50", "FF"],
-    ["23", "4E", "FF"],
-    ["23", "4A", "FF"],
-    ["23", "49", "FF"],
-    ["23", "47", "FF"],
-    ["23", "46", "FF"],
-    ["23", "43", "FF"],
-    ["23", "42", "FF"],
-    ["23", "3E", "FF"],
-    ["22", "3D", "FF"],
-    ["22", "3A", "FF"],
-    ["22", "39", "FF"],
-    ["22", "36", "FF"],
-    ["22", "35", "FF"],
-    ["22", "32", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["24", "63", "FE"],
-    ["24", "61", "FE"],
-    ["24", "5E", "FE"],
-    ["24", "5D", "FE"],
-    ["24", "5A", "FE"],
-    ["24", "59", "FE"],
-    ["FF", "FF", "FF"],
-    ["1E", "46", "D4"],
-    ["1E", "43", "D4"],
-    ["1E", "43", "D4"],
-    ["23", "4E", "FF"],
-    ["23", "4C", "FF"],
-    ["23", "48", "FF"],
-    ["23", "47", "FF"],
-    ["23", "45", "FF"],
-    ["23", "44", "FF"],
-    ["23", "41", "FF"],
-    ["23", "40", "FF"],
-    ["22", "3C", "FF"],
-    ["22", "3B", "FF"],
-    ["22", "38", "FF"],
-    ["22", "37", "FF"],
-    ["22", "34", "FF"],
-    ["22", "33", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["24", "58", "FE"],
-    ["24", "57", "FE"],
-    ["23", "54", "FF"],
-    ["23", "52", "FF"],
-    ["FF", "FF", "FF"],
-    ["1E", "41", "D4"],
-    ["1E", "3F", "D4"],
-    ["1E", "3E", "D4"],
-    ["1E", "3B", "D4"],
-    ["1E", "3B", "D4"],
-    ["23", "42", "FF"],
-    ["23", "41", "FF"],
-    ["23", "3E", "FF"],
-    ["23", "3E", "FF"],
-    ["22", "3B", "FF"],
-    ["22", "3A", "FF"],
-    ["22", "36", "FF"],
-    ["22", "34", "FF"],
-    ["22", "32", "FF"],
-    ["22", "31", "FF"],
-    ["22", "2E", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["24", "56", "FE"],
-    ["23", "54", "FF"],
-    ["23", "52", "FF"],
-    ["23", "50", "FF"],
-    ["FF", "FF", "FF"],
-    ["1E", "3F", "D4"],
-    ["1E", "3D", "D4"],
-    ["1E", "3C", "D4"],
-    ["1E", "3A", "D4"],
-    ["1E", "39", "D4"],
-    ["23", "40", "FF"],
-    ["23", "3F", "FF"],
-    ["22", "3C", "FF"],
-    ["22", "3C", "FF"],
-    ["22", "39", "FF"],
-    ["22", "38", "FF"],
-    ["22", "34", "FF"],
-    ["22", "32", "FF"],
-    ["22", "30", "FF"],
-    ["22", "2F", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["23", "4E", "FF"],
-    ["23", "4C", "FF"],
-    ["FF", "FF", "FF"],
-    ["1E", "3C", "D4"],
-    ["1E", "3A", "D4"],
-    ["1E", "39", "D4"],
-    ["1E", "37", "D4"],
-    ["1E", "36", "D4"],
-    ["1D", "33", "D4"],
-    ["22", "3B", "FF"],
-    ["22", "38", "FF"],
-    ["22", "38", "FF"],
-    ["22", "35", "FF"],
-    ["22", "34", "FF"],
-    ["22", "30", "FF"],
-    ["22", "2E", "FF"],
-    ["22", "2C", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["23", "4C", "FF"],
-    ["23", "4A", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["D1", "D1", "D6"],
-    ["0A", "0D", "27"],
-    ["E0", "E1", "E4"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["0A", "0D", "27"],
-    ["0A", "0D", "27"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-    ["FF", "FF", "FF"],
-];
@@ -1,80 +1,248 @@
-use std::{f32::consts::PI, mem::MaybeUninit};
+use std::{f32::consts::PI, mem::MaybeUninit, rc::Rc};
 
-use nalgebra::{Rotation3, Transform3, Translation3, Vector3};
+use nalgebra::{Matrix4, Point3, Rotation3, Transform3, Translation3, Vector3};
 
 use gamercade_rs::prelude as gc;
-use gamercade_rs::raw;
 
+mod assets;
+mod blend;
+mod clipping;
+mod console;
 mod gpu;
 mod graphics;
 mod image;
 mod pipeline;
+mod points;
+mod sampler;
+mod scene;
 mod shaders;
 mod shapes;
+mod texture;
+mod tiles;
+mod topology;
+mod transform;
 mod types;
 
-use shaders::bind_model_matrix;
-use shaders::Textured;
-use shaders::{vertex_shader, ColorBlend, DefaultGeometryShader, DefaultVertexShader};
-use shapes::plane;
-use shapes::PLANE_INDICES;
-use shapes::PLANE_UVS;
-use shapes::{cube, CUBE_COLORS, CUBE_INCIDES, CUBE_UVS, SIDE};
+// The renderer, for games built on top of it. The modules stay
+// private, so this is everything outside of the crate can reach.
+pub use blend::BlendMode;
+pub use clipping::{DEFAULT_GUARD_BAND, MAX_GUARD_BAND};
+pub use console::{Console, Gamercade};
+pub use gpu::{DepthCompare, DepthState, Gpu, RenderTarget};
+pub use graphics::{Interpolation, PolygonMode, Rasterizer};
+pub use pipeline::{Pipeline, Shaders};
+pub use points::{PointSize, PointSprites};
+pub use sampler::{AddressMode, Filter, MipmapMode, Sampler};
+pub use scene::{Mesh, Model, Node, Scene};
+pub use shaders::{
+    Barycentric, ColorBlend, DefaultGeometryShader, DefaultVertexShader, Derivatives, FlatNormals,
+    FrameUniforms, GeometryShader, IdColor, Light, Lighting, Lit, LitVertexShader, PixelShader,
+    RoundSprite, Subdivide, Textured, TransformUniforms, TriangleFilter, TriangleId, VertexShader,
+    Wireframe, LIT_PARAMETERS,
+};
+pub use texture::{Texture, TextureHandle, Textures};
+pub use topology::{IndexBuffer, Indices, Primitives, Topology};
+pub use types::{Color, IndexedTriangle, RawPoint, Triangle, TriangleEdge, TriangleVertex};
 
-use gpu::Gpu;
-use pipeline::Pipeline;
-use types::{IndexedTriangle, RawPoint};
+use shapes::plane;
+use shapes::{
+    cube, cube_normals, faceted_cube, faceted_cube_normals, faceted_cube_uvs, sphere_points,
+    CUBE_COLORS, CUBE_EDGES, FACETED_CUBE_INDICES, SIDE,
+};
+use shapes::{PLANE_NORMAL, PLANE_UVS};
+use tiles::DEFAULT_TILE_SIZE;
 
 pub struct GameState {
     pub screen_width: usize,
     pub screen_height: usize,
     pub dt: f32,
-    pub vertex_data: Box<[RawPoint<2>]>,
-    pub index_data: Box<[IndexedTriangle]>,
+    pub textures: Textures,
+    pub scene: Scene<2, LIT_DIMENSION>,
+    pub spin: f32,
+    // Outline drawn around the spinning cube, like a collision shape
+    pub bounds_vertices: Box<[RawPoint<3>]>,
+    pub show_bounds: bool,
+    pub stars: Box<[RawPoint<3>]>,
     // pub roll: f32,
     // pub pitch: f32,
     // pub yaw: f32,
     pub rot_x: f32,
     pub rot_y: f32,
     pub camera_position: Vector3<f32>,
+    pub frame_uniforms: FrameUniforms,
 }
 
+// The console drives the game through the init, update and draw
+// exports below, so global state has to live in static muts
 static mut GAME_STATE: MaybeUninit<GameState> = MaybeUninit::uninit();
-static mut PIPELINE: MaybeUninit<Pipeline<2, 2, 2>> = MaybeUninit::uninit();
+// The demo's uvs, followed by the parameters added for lighting
+const LIT_DIMENSION: usize = 2 + LIT_PARAMETERS;
+
+static mut PIPELINE: MaybeUninit<Pipeline<2, LIT_DIMENSION, LIT_DIMENSION>> = MaybeUninit::uninit();
+static mut LINE_PIPELINE: MaybeUninit<Pipeline<3, 3, 3>> = MaybeUninit::uninit();
+// Star colors, followed by the uvs on each sprite
+static mut POINT_PIPELINE: MaybeUninit<Pipeline<3, 3, 5>> = MaybeUninit::uninit();
 static mut GPU: MaybeUninit<Gpu> = MaybeUninit::uninit();
 
 const ROT_SPEED: f32 = PI * 0.01;
+const SPIN_SPEED: f32 = PI * 0.25;
+
+// The palette from the .gce file the moon's sprite is drawn with,
+// the later palettes have the brighter reds
+const MOON_PALETTE: usize = 200;
+const _: () = assert!(
+    MOON_PALETTE < assets::PALETTES.len(),
+    "the .gce file doesn't have the moon's palette"
+);
+// The moon's sprite, in the first sprite sheet
+const MOON_SPRITE: usize = 0;
+const _: () = assert!(
+    !assets::SPRITE_SHEETS.is_empty() && MOON_SPRITE < assets::SPRITE_SHEETS[0].count,
+    "the .gce file doesn't have the moon's sprite"
+);
 
 /// # Safety
 /// This function calls external Gamercade Api Functions
 #[no_mangle]
+#[allow(static_mut_refs)]
 pub unsafe extern "C" fn init() {
-    let vertex_data_uvs = cube(SIDE)
+    let vertex_data_uvs = faceted_cube(SIDE)
         .into_iter()
-        .zip(CUBE_UVS.iter())
-        .map(|(position, uvs)| RawPoint {
+        .zip(faceted_cube_normals())
+        .zip(faceted_cube_uvs())
+        .map(|((position, normal), uvs)| RawPoint {
             position,
-            parameters: *uvs,
+            normal,
+            parameters: uvs,
         })
         .collect::<Vec<_>>()
         .into_boxed_slice();
 
     let vertex_data_colored = cube(SIDE)
         .into_iter()
+        .zip(cube_normals())
         .zip(CUBE_COLORS.iter())
-        .map(|(position, color)| RawPoint {
+        .map(|((position, normal), color)| RawPoint {
             position,
+            normal,
             parameters: *color,
         })
         .collect::<Vec<_>>()
         .into_boxed_slice();
 
+    // The uvs go past 1 so the checkerboard repeats across the plane
     let vertex_data_plane = plane(SIDE)
         .into_iter()
         .zip(PLANE_UVS.iter())
-        .map(|(position, color)| RawPoint {
+        .map(|(position, uv)| RawPoint {
             position,
-            parameters: *color,
+            normal: PLANE_NORMAL,
+            parameters: uv * 4.0,
+        })
+        .collect::<Vec<_>>()
+        .into_boxed_slice();
+
+    // A warm light from above, an orange glow beside the
+    // cube, and a spotlight shining on it from the front
+    let lighting = Lighting {
+        ambient: Vector3::new(0.15, 0.15, 0.2),
+        lights: vec![
+            Light::Directional {
+                direction: Vector3::new(-0.3, -1.0, -0.5),
+                color: Vector3::new(0.5, 0.5, 0.45),
+            },
+            Light::Point {
+                position: Point3::new(1.5, 1.0, -1.5),
+                color: Vector3::new(1.0, 0.6, 0.3),
+                range: 5.0,
+            },
+            Light::Spot {
+                position: Point3::new(0.0, 0.0, 3.0),
+                direction: Vector3::new(0.0, 0.0, -1.0),
+                color: Vector3::new(0.6, 0.7, 1.0),
+                range: 10.0,
+                inner_angle: 10f32.to_radians(),
+                outer_angle: 20f32.to_radians(),
+            },
+        ],
+    };
+
+    let mut textures = Textures::default();
+    let image = textures.add(image::texture().with_mipmaps());
+    let checkerboard = textures.add(
+        Texture::checkerboard(8, 8, 4, Color::new(200, 200, 200), Color::new(90, 90, 110))
+            .with_mipmaps(),
+    );
+    // The editor's sprite shows off a whole palette
+    let sheet = &assets::SPRITE_SHEETS[0];
+    let sprite = textures.add(Texture::from_sprite(
+        sheet.width,
+        sheet.height,
+        sheet.sprites,
+        MOON_SPRITE,
+        &assets::PALETTES[MOON_PALETTE],
+    ));
+
+    // A spinning cube, with a smaller one orbiting it,
+    // in front of a larger checkered plane as a backdrop
+    let material: Rc<dyn PixelShader<LIT_DIMENSION>> = Rc::new(Lit::<_, 2>::new(
+        Textured::new(textures.get(image).clone()).with_sampler(Sampler {
+            mipmap_mode: MipmapMode::Nearest,
+            ..Sampler::default()
+        }),
+        lighting.clone(),
+    ));
+    let moon_material: Rc<dyn PixelShader<LIT_DIMENSION>> = Rc::new(Lit::<_, 2>::new(
+        Textured::new(textures.get(sprite).clone()),
+        lighting.clone(),
+    ));
+    let backdrop_material: Rc<dyn PixelShader<LIT_DIMENSION>> = Rc::new(Lit::<_, 2>::new(
+        Textured::new(textures.get(checkerboard).clone()).with_sampler(Sampler {
+            mipmap_mode: MipmapMode::Linear,
+            ..Sampler::new(AddressMode::Repeat, Filter::Nearest)
+        }),
+        lighting,
+    ));
+    let cube_mesh = Rc::new(Mesh::new(vertex_data_uvs, FACETED_CUBE_INDICES));
+    let cube_model = Model::new(cube_mesh.clone(), material);
+    let plane_model = Model::new(
+        Rc::new(
+            Mesh::new(vertex_data_plane, IndexBuffer::None).with_topology(Topology::TriangleFan),
+        ),
+        backdrop_material,
+    );
+
+    let moon = Node::new(
+        Some(Model::new(cube_mesh, moon_material)),
+        Transform3::from_matrix_unchecked(
+            Matrix4::new_translation(&Vector3::new(1.5, 0.0, 0.0)) * Matrix4::new_scaling(0.35),
+        ),
+    );
+    let backdrop = Node::new(
+        Some(plane_model),
+        Transform3::from_matrix_unchecked(
+            Matrix4::new_translation(&Vector3::new(0.0, 0.0, -3.0)) * Matrix4::new_scaling(3.0),
+        ),
+    );
+    let scene = Scene {
+        nodes: vec![
+            Node::new(Some(cube_model), Transform3::identity()).with_child(moon),
+            backdrop,
+        ],
+    };
+
+    // Faintly blue and yellow stars surrounding everything
+    let stars = sphere_points(300, 50.0)
+        .into_iter()
+        .enumerate()
+        .map(|(index, position)| RawPoint {
+            position,
+            normal: -position.coords.normalize(),
+            parameters: match index % 3 {
+                0 => Vector3::new(0.7, 0.8, 1.0),
+                1 => Vector3::new(1.0, 0.95, 0.7),
+                _ => Vector3::new(0.9, 0.9, 0.9),
+            },
         })
         .collect::<Vec<_>>()
         .into_boxed_slice();
@@ -82,29 +250,89 @@ pub unsafe extern "C" fn init() {
     let screen_width = gc::width();
     let screen_height = gc::height();
 
-    PIPELINE.write(Pipeline::new(screen_width, screen_height));
+    PIPELINE.write(Pipeline::new());
+    LINE_PIPELINE.write(Pipeline::new());
+    POINT_PIPELINE.write(Pipeline::new());
     GPU.write(Gpu::new(screen_width, screen_height));
 
-    vertex_shader::init_projection(screen_width, screen_height);
-    bind_model_matrix(Transform3::identity());
-
     GAME_STATE.write(GameState {
         screen_width,
         screen_height,
         dt: gc::frame_time(),
-        vertex_data: vertex_data_uvs,
-        index_data: Box::new(CUBE_INCIDES),
+        textures,
+        scene,
+        spin: 0.0,
+        bounds_vertices: vertex_data_colored,
+        show_bounds: false,
+        stars,
         rot_x: 0.0,
         rot_y: 0.0,
         camera_position: Vector3::new(0.0, 0.0, 2.0),
+        frame_uniforms: FrameUniforms::new(screen_width, screen_height),
     });
 }
 
 /// # Safety
 /// This function calls external Gamercade Api Functions
 #[no_mangle]
+#[allow(static_mut_refs)]
 pub unsafe extern "C" fn update() {
     let game_state = GAME_STATE.assume_init_mut();
+    let pipeline = PIPELINE.assume_init_mut();
+    let gpu = GPU.assume_init_mut();
+
+    // Toggle rendering at half resolution, the frame
+    // is stretched to fill the screen when presented
+    if Some(true) == gc::button_left_shoulder_pressed(0) {
+        let (width, height) = match gpu.size() {
+            (width, _) if width == game_state.screen_width => {
+                (game_state.screen_width / 2, game_state.screen_height / 2)
+            }
+            _ => (game_state.screen_width, game_state.screen_height),
+        };
+        gpu.resize(width, height, &mut game_state.frame_uniforms);
+    }
+
+    // Swap between rasterizers to compare them
+    if Some(true) == gc::button_select_pressed(0) {
+        let rasterizer = match pipeline.rasterizer() {
+            Rasterizer::Scanline => Rasterizer::EdgeFunction,
+            Rasterizer::EdgeFunction => Rasterizer::Scanline,
+        };
+        pipeline.set_rasterizer(rasterizer);
+    }
+
+    // Swap between perspective correct and affine texturing
+    if Some(true) == gc::button_right_shoulder_pressed(0) {
+        let interpolation = match pipeline.interpolation() {
+            Interpolation::Perspective => Interpolation::Affine,
+            Interpolation::Affine => Interpolation::Perspective,
+        };
+        pipeline.set_interpolation(interpolation);
+    }
+
+    // Toggle the tiled backend
+    if Some(true) == gc::button_start_pressed(0) {
+        let tile_size = match pipeline.tile_size() {
+            Some(_) => None,
+            None => Some(DEFAULT_TILE_SIZE),
+        };
+        pipeline.set_tile_size(tile_size);
+    }
+
+    // Swap between filled triangles and wireframes
+    if Some(true) == gc::button_left_stick_pressed(0) {
+        let polygon_mode = match pipeline.polygon_mode() {
+            PolygonMode::Fill => PolygonMode::Line,
+            PolygonMode::Line => PolygonMode::Fill,
+        };
+        pipeline.set_polygon_mode(polygon_mode);
+    }
+
+    // Toggle the outline around the spinning cube
+    if Some(true) == gc::button_right_stick_pressed(0) {
+        game_state.show_bounds = !game_state.show_bounds;
+    }
 
     if Some(true) == gc::button_up_held(0) {
         game_state.rot_y += ROT_SPEED;
@@ -144,25 +372,227 @@ pub unsafe extern "C" fn update() {
     let view =
         Transform3::identity() * camera_rot * Translation3::from(-game_state.camera_position);
 
-    vertex_shader::bind_view_matrix(view);
+    game_state.frame_uniforms.view = view;
+
+    // Spinning the cube carries its child around with it
+    game_state.spin += SPIN_SPEED * game_state.dt;
+    if let Some(cube) = game_state.scene.nodes.first_mut() {
+        cube.transform = Transform3::identity() * Rotation3::new(Vector3::y() * game_state.spin);
+    }
 }
 
 /// # Safety
 /// This function calls external Gamercade Api Functions
 #[no_mangle]
+#[allow(static_mut_refs)]
 pub unsafe extern "C" fn draw() {
     // Some local working data
     let game_state = GAME_STATE.assume_init_ref();
     let pipeline = PIPELINE.assume_init_mut();
+    let line_pipeline = LINE_PIPELINE.assume_init_mut();
+    let point_pipeline = POINT_PIPELINE.assume_init_mut();
     let gpu = GPU.assume_init_mut();
+    let depth_state = DepthState::default();
 
     // Clear the screen every frame
-    raw::clear_screen(0);
-    gpu.clear_z_buffer();
+    gpu.clear_color_buffer(Color::new(0, 0, 0));
+    gpu.clear_z_buffer(&depth_state);
+
+    // Each face is lit evenly, whatever normals its mesh has
+    pipeline.render_scene_graph(
+        &game_state.scene,
+        &LitVertexShader,
+        &FlatNormals::<2>,
+        &game_state.frame_uniforms,
+        gpu.render_target(depth_state),
+    );
 
-    pipeline.render_scene::<DefaultVertexShader, DefaultGeometryShader, Textured>(
-        &game_state.vertex_data,
-        &game_state.index_data,
-        &mut gpu.z_buffer,
+    // Stars are blended on top, so they come after everything solid
+    point_pipeline.render_points(
+        &game_state.stars,
+        Shaders::new(
+            &DefaultVertexShader,
+            &RoundSprite::<_, 3> {
+                material: ColorBlend::default(),
+            },
+        ),
+        &game_state.frame_uniforms.with_model(Transform3::identity()),
+        &game_state.frame_uniforms,
+        &PointSprites {
+            blend_mode: BlendMode::Additive,
+            ..PointSprites::new(PointSize::Pixels(3.0))
+        },
+        gpu.render_target(depth_state),
     );
+
+    // Slightly larger than the cube, so the outline isn't hidden by its faces
+    if let (true, Some(cube)) = (game_state.show_bounds, game_state.scene.nodes.first()) {
+        let model = cube.transform * Transform3::from_matrix_unchecked(Matrix4::new_scaling(1.1));
+        line_pipeline.render_lines(
+            &game_state.bounds_vertices,
+            CUBE_EDGES,
+            Shaders::new(&DefaultVertexShader, &ColorBlend::default()),
+            &game_state.frame_uniforms.with_model(model),
+            gpu.render_target(depth_state),
+        );
+    }
+
+    gpu.present(&mut Gamercade);
+}
+
+// Renders the demo scenes without the console, and compares them against
+// the images in the golden folder. Run with UPDATE_GOLDEN=1 to regenerate
+// them after an intended change to the output.
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use nalgebra::{Point3, SVector};
+
+    use super::*;
+    use crate::console::HeadlessConsole;
+    use crate::gpu::ColorBuffer;
+    use crate::shapes::{CUBE_INCIDES, CUBE_UVS};
+    use crate::types::IndexedTriangle;
+
+    const WIDTH: usize = 128;
+    const HEIGHT: usize = 72;
+
+    fn mesh<const D: usize>(
+        positions: impl IntoIterator<Item = Point3<f32>>,
+        parameters: &[SVector<f32, D>],
+    ) -> Vec<RawPoint<D>> {
+        positions
+            .into_iter()
+            .zip(parameters.iter())
+            .map(|(position, parameters)| RawPoint {
+                position,
+                normal: Vector3::zeros(),
+                parameters: *parameters,
+            })
+            .collect()
+    }
+
+    fn render(
+        camera_position: Vector3<f32>,
+        vertices: &[RawPoint<2>],
+        indices: &[IndexedTriangle],
+    ) -> Gpu {
+        let mut gpu = Gpu::new(WIDTH, HEIGHT);
+        gpu.clear_color_buffer(Color::new(0, 0, 0));
+        render_into(&mut gpu, camera_position, vertices, indices);
+        gpu
+    }
+
+    // Draws with the camera looking at the origin, at the Gpu's resolution
+    fn render_into(
+        gpu: &mut Gpu,
+        camera_position: Vector3<f32>,
+        vertices: &[RawPoint<2>],
+        indices: &[IndexedTriangle],
+    ) {
+        let (width, height) = gpu.size();
+        let mut frame_uniforms = FrameUniforms::new(width, height);
+
+        let forward = -camera_position.normalize();
+        let camera_rot = Rotation3::look_at_rh(&forward, &Vector3::y_axis());
+        frame_uniforms.view =
+            Transform3::identity() * camera_rot * Translation3::from(-camera_position);
+
+        let depth_state = DepthState::default();
+        gpu.clear_z_buffer(&depth_state);
+
+        Pipeline::<2, 2, 2>::new().render_scene(
+            vertices,
+            indices,
+            Shaders::new(&DefaultVertexShader, &Textured::default()),
+            &frame_uniforms.with_model(Transform3::identity()),
+            gpu.render_target(depth_state),
+        );
+    }
+
+    fn assert_golden(name: &str, color_buffer: &ColorBuffer) {
+        let path: PathBuf = [env!("CARGO_MANIFEST_DIR"), "golden", &format!("{name}.ppm")]
+            .iter()
+            .collect();
+        let image = color_buffer.to_ppm();
+
+        if std::env::var_os("UPDATE_GOLDEN").is_some() {
+            std::fs::write(&path, &image).unwrap();
+            return;
+        }
+
+        let golden = std::fs::read(&path)
+            .unwrap_or_else(|_| panic!("missing {}, run with UPDATE_GOLDEN=1", path.display()));
+        assert!(
+            golden == image,
+            "{name} doesn't match {}, run with UPDATE_GOLDEN=1 if this is intended",
+            path.display()
+        );
+    }
+
+    #[test]
+    fn textured_cube() {
+        let vertices = mesh(cube(SIDE), CUBE_UVS);
+        let gpu = render(Vector3::new(1.2, 0.9, 1.8), &vertices, &CUBE_INCIDES);
+        assert_golden("cube", &gpu.color_buffer);
+    }
+
+    #[test]
+    fn textured_plane() {
+        let vertices = mesh(plane(SIDE), PLANE_UVS);
+        let gpu = render(
+            Vector3::new(0.6, 0.4, 2.2),
+            &vertices,
+            shapes::PLANE_INDICES,
+        );
+        assert_golden("plane", &gpu.color_buffer);
+    }
+
+    #[test]
+    fn resized_frames_are_scaled_to_the_console() {
+        // Neither side divides the console's, and the aspect ratio differs,
+        // so the blocks each pixel is scaled up to vary in size
+        let mut gpu = Gpu::new(WIDTH, HEIGHT);
+        gpu.resize(48, 20, &mut FrameUniforms::new(WIDTH, HEIGHT));
+        assert_eq!(gpu.size(), (48, 20));
+
+        let background = Color::new(40, 40, 60);
+        gpu.clear_color_buffer(background);
+        let vertices = mesh(plane(SIDE), PLANE_UVS);
+        render_into(
+            &mut gpu,
+            Vector3::new(0.6, 0.4, 2.2),
+            &vertices,
+            shapes::PLANE_INDICES,
+        );
+
+        let drawn = gpu
+            .color_buffer
+            .pixels()
+            .iter()
+            .filter(|color| **color != background)
+            .count();
+        assert!(drawn > 100, "only {drawn} pixels drawn");
+
+        let mut console = HeadlessConsole::new(WIDTH, HEIGHT);
+        gpu.present(&mut console);
+
+        // Every screen pixel shows the last pixel whose block starts at or before it
+        let owner = |screen: usize, size: usize, screen_size: usize| {
+            ((screen + 1) * size).div_ceil(screen_size) - 1
+        };
+        for y in 0..HEIGHT {
+            for x in 0..WIDTH {
+                let color = gpu
+                    .color_buffer
+                    .get(owner(x, 48, WIDTH), owner(y, 20, HEIGHT));
+                assert_eq!(
+                    console.get_pixel(x, y).0,
+                    color.to_graphics_params().0,
+                    "({x}, {y})"
+                );
+            }
+        }
+    }
 }
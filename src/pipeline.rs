@@ -1,115 +1,1526 @@
-use nalgebra::Vector4;
+use nalgebra::Matrix3;
 
 use crate::{
-    gpu::ZBuffer,
-    graphics::draw_triangle,
-    shaders::{vertex_shader, GeometryShader, PixelShader, VertexShader},
-    types::{IndexedTriangle, RawPoint, Triangle, TriangleVertex},
+    blend::BlendMode,
+    clipping::Clipper,
+    gpu::RenderTarget,
+    graphics::{draw_line, Interpolation, PolygonMode, Rasterizer},
+    points::PointSprites,
+    scene::Scene,
+    shaders::{
+        DefaultGeometryShader, FrameUniforms, GeometryShader, PixelShader, TransformUniforms,
+        VertexShader,
+    },
+    tiles::Tiler,
+    topology::Primitives,
+    types::{Line, RawPoint, Triangle, TriangleEdge, TriangleVertex},
 };
 
+// The shaders a draw runs. Lines and points don't go through a geometry
+// shader, so draws of them only take the default one from new.
+pub struct Shaders<'a, VS, GS, PS: ?Sized> {
+    pub vertex: &'a VS,
+    pub geometry: &'a GS,
+    pub pixel: &'a PS,
+}
+
+impl<'a, VS, PS: ?Sized> Shaders<'a, VS, DefaultGeometryShader, PS> {
+    // Passes triangles from the vertex shader straight to the pixel shader
+    pub fn new(vertex: &'a VS, pixel: &'a PS) -> Self {
+        Self {
+            vertex,
+            geometry: &DefaultGeometryShader,
+            pixel,
+        }
+    }
+}
+
+impl<'a, VS, GS, PS: ?Sized> Shaders<'a, VS, GS, PS> {
+    pub fn with_geometry<G>(self, geometry: &'a G) -> Shaders<'a, VS, G, PS> {
+        Shaders {
+            vertex: self.vertex,
+            geometry,
+            pixel: self.pixel,
+        }
+    }
+}
+
 pub struct Pipeline<const VSIN: usize, const GSIN: usize, const PSIN: usize> {
     gs_input: Vec<TriangleVertex<GSIN>>,
     triangle_buffer: Vec<Triangle<GSIN>>,
     ps_input: Vec<Triangle<PSIN>>,
+    clipper: Clipper<PSIN>,
+    clip_output: Vec<Triangle<PSIN>>,
+    line_vertices: Vec<TriangleVertex<PSIN>>,
+    line_output: Vec<Line<PSIN>>,
+    rasterizer: Rasterizer,
+    interpolation: Interpolation,
+    polygon_mode: PolygonMode,
+    tiler: Option<Tiler>,
+}
 
-    screen_width: usize,
-    screen_height: usize,
+impl<const VSIN: usize, const GSIN: usize, const PSIN: usize> Default
+    for Pipeline<VSIN, GSIN, PSIN>
+{
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<const VSIN: usize, const GSIN: usize, const PSIN: usize> Pipeline<VSIN, GSIN, PSIN> {
-    pub fn new(screen_width: usize, screen_height: usize) -> Self {
+    // The pipeline renders at whatever size the Gpu passed to it is
+    pub fn new() -> Self {
         Self {
-            screen_width,
-            screen_height,
             gs_input: Vec::new(),
             triangle_buffer: Vec::new(),
             ps_input: Vec::new(),
+            clipper: Clipper::default(),
+            clip_output: Vec::new(),
+            line_vertices: Vec::new(),
+            line_output: Vec::new(),
+            rasterizer: Rasterizer::default(),
+            interpolation: Interpolation::default(),
+            polygon_mode: PolygonMode::default(),
+            tiler: None,
         }
     }
 
+    // Sets how far triangles may extend past the screen, as a multiple
+    // of its size, before they are clipped instead of just scissored.
+    // Clamped between 1, the screen itself, and MAX_GUARD_BAND.
+    pub fn set_guard_band(&mut self, guard_band: f32) {
+        self.clipper.set_guard_band(guard_band);
+    }
+
+    pub fn rasterizer(&self) -> Rasterizer {
+        self.rasterizer
+    }
+
+    pub fn set_rasterizer(&mut self, rasterizer: Rasterizer) {
+        self.rasterizer = rasterizer;
+    }
+
+    pub fn interpolation(&self) -> Interpolation {
+        self.interpolation
+    }
+
+    // Applies to every following draw, until changed again
+    pub fn set_interpolation(&mut self, interpolation: Interpolation) {
+        self.interpolation = interpolation;
+    }
+
+    pub fn polygon_mode(&self) -> PolygonMode {
+        self.polygon_mode
+    }
+
+    // Switches between filled triangles and wireframes,
+    // which also applies to transparent scenes
+    pub fn set_polygon_mode(&mut self, polygon_mode: PolygonMode) {
+        self.polygon_mode = polygon_mode;
+    }
+
+    pub fn tile_size(&self) -> Option<usize> {
+        self.tiler.as_ref().map(Tiler::tile_size)
+    }
+
+    // Switches to the tiled backend with tiles of the given size,
+    // or back to drawing triangles one at a time with None
+    pub fn set_tile_size(&mut self, tile_size: Option<usize>) {
+        self.tiler = tile_size.map(Tiler::new);
+    }
+
+    // Draws the triangles made from the vertices by the primitives, which
+    // can be a slice of IndexedTriangles for a plain triangle list
     pub fn render_scene<
+        'a,
+        VS: VertexShader<VSIN, GSIN>,
+        GS: GeometryShader<GSIN, PSIN>,
+        PS: PixelShader<PSIN> + ?Sized,
+    >(
+        &mut self,
+        raw_vertices: &[RawPoint<VSIN>],
+        primitives: impl Into<Primitives<'a>>,
+        shaders: Shaders<VS, GS, PS>,
+        uniforms: &VS::Uniforms,
+        target: RenderTarget,
+    ) {
+        self.process_geometry(
+            &shaders,
+            raw_vertices,
+            primitives.into(),
+            uniforms,
+            target.size(),
+        );
+        self.rasterize(shaders.pixel, target);
+    }
+
+    // Draws a line for each edge. Lines don't go through the geometry
+    // shader, so the vertex shader outputs the pixel shader's parameters.
+    pub fn render_lines<VS: VertexShader<VSIN, PSIN>, PS: PixelShader<PSIN> + ?Sized>(
+        &mut self,
+        raw_vertices: &[RawPoint<VSIN>],
+        raw_edges: &[TriangleEdge],
+        shaders: Shaders<VS, DefaultGeometryShader, PS>,
+        uniforms: &VS::Uniforms,
+        target: RenderTarget,
+    ) {
+        self.line_vertices.clear();
+        self.line_vertices.extend(
+            raw_vertices
+                .iter()
+                .map(|raw_vertex| shaders.vertex.run(raw_vertex, uniforms)),
+        );
+
+        let line_vertices = &self.line_vertices;
+        let lines = raw_edges.iter().map(|edge| Line {
+            vertices: [line_vertices[edge.0].clone(), line_vertices[edge.1].clone()],
+        });
+        add_lines(
+            lines,
+            &self.clipper,
+            &mut self.line_output,
+            target.size(),
+            self.interpolation,
+        );
+
+        self.rasterize(shaders.pixel, target);
+    }
+
+    // Draws each vertex as a sprite, a quad facing the screen. The vertex
+    // shader's output is passed on to the pixel shader, followed by the
+    // uvs on the sprite, so PSIN must be GSIN + 2. World sized points are
+    // scaled with the frame's projection.
+    pub fn render_points<VS: VertexShader<VSIN, GSIN>, PS: PixelShader<PSIN> + ?Sized>(
+        &mut self,
+        raw_vertices: &[RawPoint<VSIN>],
+        shaders: Shaders<VS, DefaultGeometryShader, PS>,
+        uniforms: &VS::Uniforms,
+        frame_uniforms: &FrameUniforms,
+        sprites: &PointSprites,
+        mut target: RenderTarget,
+    ) {
+        self.gs_input.clear();
+        self.gs_input.extend(
+            raw_vertices
+                .iter()
+                .map(|raw_vertex| shaders.vertex.run(raw_vertex, uniforms)),
+        );
+
+        let screen_size = target.size();
+        let projection = &frame_uniforms.projection;
+        let ps_input = &mut self.ps_input;
+        self.gs_input
+            .iter()
+            .for_each(|point| sprites.expand(point, screen_size, projection, ps_input));
+        self.clip_triangles(screen_size);
+
+        if sprites.blend_mode != BlendMode::Opaque {
+            target.depth_state.write = false;
+        }
+        target.blend_mode = sprites.blend_mode;
+        self.rasterize(shaders.pixel, target);
+    }
+
+    // Draws see through geometry, blending it over what is already in the
+    // color buffer with the target's blend mode. Should be called after all
+    // opaque geometry is drawn. Triangles, or the lines of wireframes, are
+    // sorted back to front, and depth writes are always disabled so they
+    // don't hide each other.
+    pub fn render_transparent_scene<
+        'a,
         VS: VertexShader<VSIN, GSIN>,
         GS: GeometryShader<GSIN, PSIN>,
-        PS: PixelShader<PSIN>,
+        PS: PixelShader<PSIN> + ?Sized,
     >(
         &mut self,
         raw_vertices: &[RawPoint<VSIN>],
-        raw_indices: &[IndexedTriangle],
-        depth_buffer: &mut ZBuffer,
+        primitives: impl Into<Primitives<'a>>,
+        shaders: Shaders<VS, GS, PS>,
+        uniforms: &VS::Uniforms,
+        mut target: RenderTarget,
+    ) {
+        self.process_geometry(
+            &shaders,
+            raw_vertices,
+            primitives.into(),
+            uniforms,
+            target.size(),
+        );
+
+        // Larger z is further away after the projection
+        let depth = |vertices: &[TriangleVertex<PSIN>]| -> f32 {
+            vertices.iter().map(|vertex| vertex.position.z).sum::<f32>() / vertices.len() as f32
+        };
+        self.clip_output
+            .sort_by(|a, b| depth(&b.vertices).total_cmp(&depth(&a.vertices)));
+        self.line_output
+            .sort_by(|a, b| depth(&b.vertices).total_cmp(&depth(&a.vertices)));
+
+        target.depth_state.write = false;
+        self.rasterize(shaders.pixel, target);
+    }
+
+    // Runs every stage up to rasterization, leaving
+    // screen space triangles in the clip output
+    fn process_geometry<
+        VS: VertexShader<VSIN, GSIN>,
+        GS: GeometryShader<GSIN, PSIN>,
+        PS: PixelShader<PSIN> + ?Sized,
+    >(
+        &mut self,
+        shaders: &Shaders<VS, GS, PS>,
+        raw_vertices: &[RawPoint<VSIN>],
+        primitives: Primitives,
+        uniforms: &VS::Uniforms,
+        (screen_width, screen_height): (usize, usize),
     ) {
         // Clear the buffers
         self.gs_input.clear();
 
         // Process vertices by applying the Vertex Shader
         // to each vertex, and storing their output in gs_input
-        self.gs_input
-            .extend(raw_vertices.iter().map(|raw_vertex| VS::run(raw_vertex)));
+        self.gs_input.extend(
+            raw_vertices
+                .iter()
+                .map(|raw_vertex| shaders.vertex.run(raw_vertex, uniforms)),
+        );
 
         // Assemble our triangles, using indices
         // and place them into the triangle buffer.
-        self.triangle_buffer
-            .extend(raw_indices.iter().map(|triangle_indices| {
-                let a = self.gs_input[triangle_indices.0].clone();
-                let b = self.gs_input[triangle_indices.1].clone();
-                let c = self.gs_input[triangle_indices.2].clone();
-
-                Triangle {
-                    vertices: [a, b, c],
-                }
-            }));
+        let gs_input = &self.gs_input;
+        let triangle_buffer = &mut self.triangle_buffer;
+        primitives.for_each_triangle(gs_input.len(), |triangle_indices| {
+            let a = gs_input[triangle_indices.0].clone();
+            let b = gs_input[triangle_indices.1].clone();
+            let c = gs_input[triangle_indices.2].clone();
 
-        // Run the geometry shader on each triangle and
-        // store it in ps_input
-        self.ps_input.extend(
-            self.triangle_buffer
-                .drain(..)
-                .map(|triangle| GS::run(triangle)),
-        );
-
-        let eye_position: Vector4<f32> =
-            vertex_shader::get_projection_matrix().as_matrix() * Vector4::new(0.0, 0.0, 0.0, 1.0);
+            triangle_buffer.push(Triangle {
+                vertices: [a, b, c],
+            });
+        });
 
-        let eye_position = eye_position.xyz();
+        // Run the geometry shader on each triangle,
+        // which stores what it outputs in ps_input
+        let ps_input = &mut self.ps_input;
+        self.triangle_buffer
+            .drain(..)
+            .enumerate()
+            .for_each(|(primitive_id, triangle)| {
+                shaders.geometry.run(triangle, primitive_id, ps_input)
+            });
 
         // Do backface Culling
+        // The sign of the determinant of the x, y, w columns matches
+        // the winding of the triangle on screen, and unlike the
+        // projected winding it stays valid for vertices behind the camera.
         self.ps_input.retain(|triangle| {
-            let a = triangle.vertices[0].position.xyz();
-            let b = triangle.vertices[1].position.xyz();
-            let c = triangle.vertices[2].position.xyz();
-
-            let cross_result = (b - a).cross(&(c - a));
-            let dot_compare = a - eye_position;
-            let dot_result = cross_result.dot(&dot_compare);
-            dot_result <= 0.0
+            let [a, b, c] = &triangle.vertices;
+            let determinant = Matrix3::new(
+                a.position.x,
+                a.position.y,
+                a.position.w,
+                b.position.x,
+                b.position.y,
+                b.position.w,
+                c.position.x,
+                c.position.y,
+                c.position.w,
+            )
+            .determinant();
+            determinant < 0.0
         });
 
-        // TODO: Clip triangles
+        // Wireframes are drawn as lines, which are clipped on their own.
+        // Clipping triangles first would add edges along the clip planes.
+        let interpolation = self.interpolation;
+        if self.polygon_mode == PolygonMode::Line {
+            let lines = self.ps_input.drain(..).flat_map(|triangle| {
+                let [a, b, c] = triangle.vertices;
+                [
+                    Line {
+                        vertices: [a.clone(), b.clone()],
+                    },
+                    Line {
+                        vertices: [b, c.clone()],
+                    },
+                    Line { vertices: [c, a] },
+                ]
+            });
+            add_lines(
+                lines,
+                &self.clipper,
+                &mut self.line_output,
+                (screen_width, screen_height),
+                interpolation,
+            );
+            return;
+        }
+
+        self.clip_triangles((screen_width, screen_height));
+    }
+
+    // Clips the triangles in ps_input, and moves what is
+    // left of them to the clip output in screen space
+    fn clip_triangles(&mut self, (screen_width, screen_height): (usize, usize)) {
+        // Clip triangles against the view volume,
+        // this also guarantees w > 0 for everything after
+        let clipper = &mut self.clipper;
+        let clip_output = &mut self.clip_output;
+        self.ps_input
+            .drain(..)
+            .for_each(|triangle| clipper.clip(triangle, clip_output));
 
         //Convert the verts into screen space
-        self.ps_input.iter_mut().for_each(|triangle| {
+        let interpolation = self.interpolation;
+        self.clip_output.iter_mut().for_each(|triangle| {
             triangle
                 .vertices
                 .iter_mut()
-                .for_each(|vertex| to_ndc(vertex, self.screen_width, self.screen_height));
+                .for_each(|vertex| to_ndc(vertex, screen_width, screen_height, interpolation));
         });
+    }
+
+    // Rasterizes the triangles in the clip output
+    fn rasterize<PS: PixelShader<PSIN> + ?Sized>(
+        &mut self,
+        pixel_shader: &PS,
+        mut target: RenderTarget,
+    ) {
+        let rasterizer = self.rasterizer;
+        match &mut self.tiler {
+            Some(tiler) => {
+                tiler.draw(&self.clip_output, pixel_shader, &mut target, rasterizer);
+                self.clip_output.clear();
+            }
+            None => self.clip_output.drain(..).for_each(|triangle| {
+                rasterizer.draw(&triangle, pixel_shader, &mut target);
+            }),
+        }
+
+        self.line_output
+            .drain(..)
+            .for_each(|line| draw_line(&line, pixel_shader, &mut target));
+    }
+}
 
-        // Rasterize the triangles
-        self.ps_input.drain(..).for_each(|triangle| {
-            draw_triangle::<PS, PSIN>(triangle, depth_buffer);
+impl<const VSIN: usize, const GSIN: usize, const PSIN: usize> Pipeline<VSIN, GSIN, PSIN> {
+    // Draws every model in the scene with its world transform,
+    // and the camera and projection from the frame uniforms
+    pub fn render_scene_graph<
+        VS: VertexShader<VSIN, GSIN, Uniforms = TransformUniforms>,
+        GS: GeometryShader<GSIN, PSIN>,
+    >(
+        &mut self,
+        scene: &Scene<VSIN, PSIN>,
+        vertex_shader: &VS,
+        geometry_shader: &GS,
+        frame_uniforms: &FrameUniforms,
+        mut target: RenderTarget,
+    ) {
+        scene.visit(|model, world| {
+            self.render_scene(
+                &model.mesh.vertices,
+                model.mesh.primitives(),
+                Shaders::new(vertex_shader, model.material.as_ref()).with_geometry(geometry_shader),
+                &frame_uniforms.with_model(*world),
+                target.reborrow(),
+            );
         });
     }
 }
 
+// Clips the lines, and pushes what is left of them
+// into the output in screen space
+fn add_lines<const PSIN: usize>(
+    lines: impl Iterator<Item = Line<PSIN>>,
+    clipper: &Clipper<PSIN>,
+    output: &mut Vec<Line<PSIN>>,
+    (screen_width, screen_height): (usize, usize),
+    interpolation: Interpolation,
+) {
+    output.extend(lines.filter_map(|line| {
+        let mut line = clipper.clip_line(line)?;
+        line.vertices
+            .iter_mut()
+            .for_each(|vertex| to_ndc(vertex, screen_width, screen_height, interpolation));
+        Some(line)
+    }));
+}
+
+// Does the perspective divide and viewport transform. For perspective
+// correct interpolation the parameters are divided by w as well, and
+// 1/w is kept in position.w to recover them per pixel.
 fn to_ndc<const PSIN: usize>(
     vertex: &mut TriangleVertex<PSIN>,
     screen_width: usize,
     screen_height: usize,
+    interpolation: Interpolation,
 ) {
     let w_inverse = vertex.position.w.recip();
-    *vertex *= w_inverse;
+    vertex.position *= w_inverse;
 
     vertex.position.x = (vertex.position.x + 1.0) * (screen_width as f32 / 2.0);
     vertex.position.y = (-vertex.position.y + 1.0) * (screen_height as f32 / 2.0);
 
-    vertex.position.w = w_inverse;
+    match interpolation {
+        Interpolation::Perspective => {
+            vertex.parameters *= w_inverse;
+            vertex.position.w = w_inverse;
+        }
+        Interpolation::Affine => vertex.position.w = 1.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, f32::consts::FRAC_PI_2, rc::Rc};
+
+    use nalgebra::{Matrix4, Perspective3, Point3, SVector, Transform3, Vector2, Vector3, Vector4};
+
+    use super::*;
+    use crate::{
+        clipping::DEFAULT_GUARD_BAND,
+        gpu::{DepthState, Gpu},
+        points::PointSize,
+        scene::{Mesh, Model, Node},
+        shaders::{
+            Barycentric, DefaultVertexShader, Derivatives, IdColor, Subdivide, TriangleFilter,
+            TriangleId, Wireframe,
+        },
+        topology::{Indices, Topology},
+        types::{Color, IndexedTriangle},
+    };
+
+    const SIZE: usize = 64;
+
+    // Looks down -z with a 90 degree fov, so a point at (x, y, z)
+    // lands on (-x / z, -y / z) in ndc
+    struct Project;
+
+    fn projection() -> Perspective3<f32> {
+        Perspective3::new(1.0, FRAC_PI_2, 0.5, 100.0)
+    }
+
+    impl VertexShader<2, 2> for Project {
+        type Uniforms = ();
+
+        fn run(&self, vertex: &RawPoint<2>, _: &()) -> TriangleVertex<2> {
+            let projection = projection();
+            let position: Vector4<f32> = vertex.position.to_homogeneous();
+
+            TriangleVertex {
+                position: projection.as_matrix() * position,
+                parameters: vertex.parameters,
+            }
+        }
+    }
+
+    // A Gpu with a black background and an empty z buffer, for the
+    // default depth state which every test draws with
+    fn cleared_gpu(width: usize, height: usize) -> Gpu {
+        let mut gpu = Gpu::new(width, height);
+        gpu.clear_color_buffer(Color::new(0, 0, 0));
+        gpu.clear_z_buffer(&DepthState::default());
+        gpu
+    }
+
+    // Which pixels were drawn to, going by the black background
+    fn coverage(gpu: &Gpu) -> Vec<bool> {
+        gpu.color_buffer
+            .pixels()
+            .iter()
+            .map(|color| *color != Color::new(0, 0, 0))
+            .collect()
+    }
+
+    fn covered_pixels(gpu: &Gpu) -> usize {
+        coverage(gpu).into_iter().filter(|&covered| covered).count()
+    }
+
+    // Writes the uvs out as red and green
+    struct UvColor;
+
+    impl PixelShader<2> for UvColor {
+        fn run(&self, params: SVector<f32, 2>) -> Color {
+            let to_channel = |value: f32| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+            Color::new(to_channel(params.x), to_channel(params.y), 0)
+        }
+    }
+
+    // Writes the uvs out like UvColor, keeping the
+    // uvs and derivatives of every pixel shaded
+    #[derive(Default)]
+    struct UvDerivatives(RefCell<Vec<(Vector2<f32>, Option<Derivatives<2>>)>>);
+
+    impl PixelShader<2> for UvDerivatives {
+        fn run(&self, params: SVector<f32, 2>) -> Color {
+            self.0.borrow_mut().push((params, None));
+            UvColor.run(params)
+        }
+
+        fn uses_derivatives(&self) -> bool {
+            true
+        }
+
+        fn run_with_derivatives(
+            &self,
+            params: SVector<f32, 2>,
+            derivatives: &Derivatives<2>,
+        ) -> Color {
+            self.0.borrow_mut().push((params, Some(*derivatives)));
+            UvColor.run(params)
+        }
+    }
+
+    // Passes everything on to UvDerivatives, without opting in to derivatives
+    struct NoDerivatives<'a>(&'a UvDerivatives);
+
+    impl PixelShader<2> for NoDerivatives<'_> {
+        fn run(&self, params: SVector<f32, 2>) -> Color {
+            self.0.run(params)
+        }
+
+        fn run_with_derivatives(
+            &self,
+            params: SVector<f32, 2>,
+            derivatives: &Derivatives<2>,
+        ) -> Color {
+            self.0.run_with_derivatives(params, derivatives)
+        }
+    }
+
+    const NEAR_Z: f32 = -1.5;
+    const FAR_Z: f32 = -10.0;
+
+    // A floor at y = -1 going away from the camera, with u across
+    // and v increasing into the distance
+    fn floor() -> ([RawPoint<2>; 4], [IndexedTriangle; 2]) {
+        let point = |x: f32, z: f32, u: f32, v: f32| RawPoint {
+            position: Point3::new(x, -1.0, z),
+            normal: Vector3::y(),
+            parameters: Vector2::new(u, v),
+        };
+
+        (
+            [
+                point(-1.0, NEAR_Z, 0.0, 0.0),
+                point(1.0, NEAR_Z, 1.0, 0.0),
+                point(1.0, FAR_Z, 1.0, 1.0),
+                point(-1.0, FAR_Z, 0.0, 1.0),
+            ],
+            [IndexedTriangle(0, 2, 1), IndexedTriangle(0, 3, 2)],
+        )
+    }
+
+    // Casts a ray through the pixel center onto the floor
+    fn expected_uv(x: usize, y: usize) -> Vector2<f32> {
+        floor_uv(x as f32 + 0.5, y as f32 + 0.5)
+    }
+
+    // The uv at any point on the screen, in pixels
+    fn floor_uv(x: f32, y: f32) -> Vector2<f32> {
+        let ndc_x = x / (SIZE as f32 / 2.0) - 1.0;
+        let ndc_y = 1.0 - y / (SIZE as f32 / 2.0);
+
+        let floor_x = -ndc_x / ndc_y;
+        let floor_z = 1.0 / ndc_y;
+
+        Vector2::new((floor_x + 1.0) / 2.0, (floor_z - NEAR_Z) / (FAR_Z - NEAR_Z))
+    }
+
+    fn render(rasterizer: Rasterizer, interpolation: Interpolation) -> Gpu {
+        render_with(rasterizer, interpolation, &DefaultGeometryShader, &UvColor)
+    }
+
+    fn render_with(
+        rasterizer: Rasterizer,
+        interpolation: Interpolation,
+        geometry_shader: &impl GeometryShader<2, 2>,
+        pixel_shader: &impl PixelShader<2>,
+    ) -> Gpu {
+        let (_, indices) = floor();
+        render_primitives(
+            rasterizer,
+            interpolation,
+            &indices,
+            geometry_shader,
+            pixel_shader,
+        )
+    }
+
+    // Draws the floor's vertices with the given primitives
+    fn render_primitives<'a>(
+        rasterizer: Rasterizer,
+        interpolation: Interpolation,
+        primitives: impl Into<Primitives<'a>>,
+        geometry_shader: &impl GeometryShader<2, 2>,
+        pixel_shader: &impl PixelShader<2>,
+    ) -> Gpu {
+        let (vertices, _) = floor();
+        let mut gpu = cleared_gpu(SIZE, SIZE);
+
+        let mut pipeline = Pipeline::<2, 2, 2>::new();
+        pipeline.set_rasterizer(rasterizer);
+        pipeline.set_interpolation(interpolation);
+        pipeline.render_scene(
+            &vertices,
+            primitives,
+            Shaders::new(&Project, pixel_shader).with_geometry(geometry_shader),
+            &(),
+            gpu.render_target(DepthState::default()),
+        );
+
+        gpu
+    }
+
+    // Largest difference between the rendered and expected uvs,
+    // over a block of pixels well inside of the floor
+    fn max_uv_error(gpu: &Gpu) -> f32 {
+        let mut max_error = 0.0f32;
+
+        for y in 38..52 {
+            for x in 26..38 {
+                let color = gpu.color_buffer.get(x, y);
+                let uv = Vector2::new(color.r as f32, color.g as f32) / 255.0;
+                max_error = max_error.max((uv - expected_uv(x, y)).abs().max());
+            }
+        }
+
+        max_error
+    }
+
+    #[test]
+    fn perspective_correct_uvs() {
+        for rasterizer in [Rasterizer::Scanline, Rasterizer::EdgeFunction] {
+            let gpu = render(rasterizer, Interpolation::Perspective);
+            let error = max_uv_error(&gpu);
+            assert!(error < 0.01, "{rasterizer:?} uv error {error}");
+        }
+    }
+
+    #[test]
+    fn affine_uvs_are_warped() {
+        for rasterizer in [Rasterizer::Scanline, Rasterizer::EdgeFunction] {
+            let gpu = render(rasterizer, Interpolation::Affine);
+            let error = max_uv_error(&gpu);
+            assert!(error > 0.1, "{rasterizer:?} uv error {error}");
+        }
+    }
+
+    #[test]
+    fn rasterizers_agree() {
+        let scanline = render(Rasterizer::Scanline, Interpolation::Perspective);
+        let edge_function = render(Rasterizer::EdgeFunction, Interpolation::Perspective);
+
+        let differences = coverage(&scanline)
+            .iter()
+            .zip(coverage(&edge_function).iter())
+            .filter(|(a, b)| a != b)
+            .count();
+
+        // Both follow the top left rule, so even pixels
+        // exactly on an edge are decided the same way
+        assert_eq!(differences, 0);
+    }
+
+    #[test]
+    fn tiles_match_drawing_whole_triangles() {
+        // Neither side is a multiple of the tile size, so the
+        // tiles along the right and bottom edges are cut short
+        let render = |rasterizer, tile_size| {
+            let (vertices, indices) = floor();
+            let mut gpu = cleared_gpu(60, 44);
+
+            let mut pipeline = Pipeline::<2, 2, 2>::new();
+            pipeline.set_rasterizer(rasterizer);
+            pipeline.set_tile_size(tile_size);
+            pipeline.render_scene(
+                &vertices,
+                &indices,
+                Shaders::new(&Project, &UvColor),
+                &(),
+                gpu.render_target(DepthState::default()),
+            );
+
+            gpu
+        };
+
+        for rasterizer in [Rasterizer::Scanline, Rasterizer::EdgeFunction] {
+            let tiled = render(rasterizer, Some(16));
+            let untiled = render(rasterizer, None);
+
+            assert!(covered_pixels(&tiled) > 250);
+            assert_eq!(
+                tiled.color_buffer.pixels(),
+                untiled.color_buffer.pixels(),
+                "{rasterizer:?}"
+            );
+        }
+    }
+
+    // Half see through red where u is 1, and blue where it is 0
+    struct Tint;
+
+    impl PixelShader<2> for Tint {
+        fn run(&self, params: SVector<f32, 2>) -> Color {
+            match params.x > 0.5 {
+                true => Color::rgba(255, 0, 0, 128),
+                false => Color::rgba(0, 0, 255, 128),
+            }
+        }
+    }
+
+    // A near red square and a far blue one, both facing the camera. The near
+    // one is submitted first, and each is half_size / -z * 32 pixels across
+    // from the center.
+    fn render_transparent_squares(
+        polygon_mode: PolygonMode,
+        (near_half_size, far_half_size): (f32, f32),
+    ) -> Gpu {
+        let square = |z: f32, half_size: f32, u: f32| {
+            [(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)].map(|(x, y)| RawPoint {
+                position: Point3::new(x * half_size, y * half_size, z),
+                normal: Vector3::z(),
+                parameters: Vector2::new(u, 0.0),
+            })
+        };
+        let vertices = [
+            square(-2.0, near_half_size, 1.0),
+            square(-4.0, far_half_size, 0.0),
+        ]
+        .concat();
+        let indices = [
+            IndexedTriangle(0, 2, 1),
+            IndexedTriangle(0, 3, 2),
+            IndexedTriangle(4, 6, 5),
+            IndexedTriangle(4, 7, 6),
+        ];
+
+        let mut gpu = cleared_gpu(SIZE, SIZE);
+        let mut pipeline = Pipeline::<2, 2, 2>::new();
+        pipeline.set_polygon_mode(polygon_mode);
+        pipeline.render_transparent_scene(
+            &vertices,
+            &indices,
+            Shaders::new(&Project, &Tint),
+            &(),
+            gpu.render_target(DepthState::default())
+                .with_blend_mode(BlendMode::Alpha),
+        );
+        gpu
+    }
+
+    #[test]
+    fn transparent_triangles_are_drawn_back_to_front() {
+        let gpu = render_transparent_squares(PolygonMode::Fill, (0.5, 2.0));
+
+        // Blue goes over the black background first, then red over that
+        assert_eq!(gpu.color_buffer.get(20, 20), Color::new(0, 0, 128));
+        assert_eq!(gpu.color_buffer.get(32, 32), Color::new(128, 0, 63));
+    }
+
+    #[test]
+    fn transparent_lines_are_drawn_back_to_front() {
+        // Both squares have their top edge along row 24, from x = 24.5 to 39.5
+        let gpu = render_transparent_squares(PolygonMode::Line, (0.46875, 0.9375));
+
+        assert_eq!(gpu.color_buffer.get(32, 24), Color::new(128, 0, 63));
+    }
+
+    #[test]
+    fn picking_finds_triangles() {
+        let (vertices, indices) = floor();
+        let mut gpu = cleared_gpu(SIZE, SIZE);
+
+        Pipeline::<2, 2, 1>::new().render_scene(
+            &vertices,
+            &indices,
+            Shaders::new(&Project, &IdColor).with_geometry(&TriangleId { first_id: 10 }),
+            &(),
+            gpu.render_target(DepthState::default()),
+        );
+
+        let mut ids = gpu
+            .color_buffer
+            .pixels()
+            .iter()
+            .filter_map(|color| IdColor::decode(*color))
+            .collect::<Vec<_>>();
+        ids.sort();
+        ids.dedup();
+
+        assert_eq!(ids, [10, 11]);
+        // The first triangle covers the near right corner of the floor
+        assert_eq!(IdColor::decode(gpu.color_buffer.get(48, 50)), Some(10));
+        assert_eq!(IdColor::decode(gpu.color_buffer.get(0, 0)), None);
+    }
+
+    #[test]
+    fn derivatives_are_only_worked_out_when_used() {
+        for rasterizer in [Rasterizer::Scanline, Rasterizer::EdgeFunction] {
+            let shader = UvDerivatives::default();
+            render_with(
+                rasterizer,
+                Interpolation::Perspective,
+                &DefaultGeometryShader,
+                &NoDerivatives(&shader),
+            );
+
+            let pixels = shader.0.into_inner();
+            assert!(pixels.len() > 100);
+            assert!(
+                pixels.iter().all(|(_, derivatives)| derivatives.is_none()),
+                "{rasterizer:?} gave derivatives to a shader which doesn't use them"
+            );
+        }
+    }
+
+    #[test]
+    fn derivatives_match_neighbouring_pixels() {
+        for rasterizer in [Rasterizer::Scanline, Rasterizer::EdgeFunction] {
+            let shader = UvDerivatives::default();
+            render_with(
+                rasterizer,
+                Interpolation::Perspective,
+                &DefaultGeometryShader,
+                &shader,
+            );
+
+            let pixels = shader.0.into_inner();
+            assert!(pixels.len() > 100);
+
+            for (uv, derivatives) in pixels {
+                let derivatives = derivatives.expect("triangles should have derivatives");
+
+                // Back from the uv to where it is on screen, the reverse of floor_uv
+                let ndc_y = 1.0 / (NEAR_Z + uv.y * (FAR_Z - NEAR_Z));
+                let ndc_x = (1.0 - uv.x * 2.0) * ndc_y;
+                let half_size = SIZE as f32 / 2.0;
+                let (x, y) = ((ndc_x + 1.0) * half_size, (1.0 - ndc_y) * half_size);
+
+                let step = 0.01;
+                let dx = (floor_uv(x + step, y) - floor_uv(x - step, y)) / (2.0 * step);
+                let dy = (floor_uv(x, y + step) - floor_uv(x, y - step)) / (2.0 * step);
+
+                for (actual, expected) in [(derivatives.dx, dx), (derivatives.dy, dy)] {
+                    let error = (actual - expected).norm() / expected.norm();
+                    assert!(
+                        error < 0.02,
+                        "{rasterizer:?} derivative error {error} at {uv}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn subdivided_triangles_cover_the_same_pixels() {
+        for rasterizer in [Rasterizer::Scanline, Rasterizer::EdgeFunction] {
+            let whole = render(rasterizer, Interpolation::Perspective);
+            let subdivided =
+                render_with(rasterizer, Interpolation::Perspective, &Subdivide, &UvColor);
+
+            assert_eq!(coverage(&subdivided), coverage(&whole), "{rasterizer:?}");
+            let error = max_uv_error(&subdivided);
+            assert!(error < 0.01, "{rasterizer:?} uv error {error}");
+        }
+    }
+
+    #[test]
+    fn filtered_triangles_are_not_drawn() {
+        let gpu = render_with(
+            Rasterizer::EdgeFunction,
+            Interpolation::Perspective,
+            &TriangleFilter(|_: &Triangle<2>, primitive_id| primitive_id == 0),
+            &UvColor,
+        );
+        let black = Color::new(0, 0, 0);
+
+        // The first triangle covers the near right corner,
+        // and the second one the far left
+        assert_ne!(gpu.color_buffer.get(48, 50), black);
+        assert_eq!(gpu.color_buffer.get(26, 40), black);
+    }
+
+    #[test]
+    fn wireframes_only_draw_edges() {
+        let (vertices, indices) = floor();
+        let black = Color::new(0, 0, 0);
+        let mut gpu = cleared_gpu(SIZE, SIZE);
+
+        let mut pipeline = Pipeline::<2, 2, 2>::new();
+        pipeline.set_polygon_mode(PolygonMode::Line);
+        pipeline.render_scene(
+            &vertices,
+            &indices,
+            Shaders::new(&Project, &UvColor),
+            &(),
+            gpu.render_target(DepthState::default()),
+        );
+
+        // The near edge runs along y = 53.3, and the inside is left empty
+        assert_ne!(gpu.color_buffer.get(32, 53), black);
+        assert_eq!(gpu.color_buffer.get(48, 50), black);
+
+        // Both triangles share the diagonal, from the near left
+        // corner to the far right one, so it is drawn as well
+        let diagonal = (0..SIZE)
+            .filter(|&x| gpu.color_buffer.get(x, 44) != black)
+            .count();
+        assert_eq!(diagonal, 3, "edges crossing row 44");
+    }
+
+    // A line vertex, colored by UvColor
+    fn line_point(x: f32, y: f32, z: f32, color: Vector2<f32>) -> RawPoint<2> {
+        RawPoint {
+            position: Point3::new(x, y, z),
+            normal: Vector3::z(),
+            parameters: color,
+        }
+    }
+
+    // Which columns of a row were drawn to
+    fn covered_columns(gpu: &Gpu, y: usize) -> Vec<usize> {
+        (0..SIZE)
+            .filter(|&x| gpu.color_buffer.get(x, y) != Color::new(0, 0, 0))
+            .collect()
+    }
+
+    #[test]
+    fn lines_stop_at_the_near_plane_and_screen_edges() {
+        let red = Vector2::new(1.0, 0.0);
+        let vertices = [
+            // Down the middle of the screen along a floor just below
+            // the camera, from in front of the camera to behind it
+            line_point(0.0, -0.2, -4.0, red),
+            line_point(0.0, -0.2, 2.0, red),
+            // Along row 24, from three screen widths past the left edge
+            line_point(-6.0, 0.46875, -2.0, red),
+            line_point(0.53125, 0.46875, -2.0, red),
+        ];
+
+        let mut gpu = cleared_gpu(SIZE, SIZE);
+        Pipeline::<2, 2, 2>::new().render_lines(
+            &vertices,
+            &[TriangleEdge(0, 1), TriangleEdge(2, 3)],
+            Shaders::new(&Project, &UvColor),
+            &(),
+            gpu.render_target(DepthState::default()),
+        );
+
+        // The floor line goes from y = 33.6 at its far end down to y = 44.8,
+        // where the near plane cuts it. Unclipped, the vertex behind the
+        // camera would fling it up and over the top half of the screen.
+        for y in 0..SIZE {
+            let expected = match y {
+                24 => (0..=40).collect(),
+                33..=44 => vec![32],
+                _ => vec![],
+            };
+            assert_eq!(covered_columns(&gpu, y), expected, "row {y}");
+        }
+    }
+
+    #[test]
+    fn lines_are_depth_tested() {
+        let (red, green) = (Vector2::new(1.0, 0.0), Vector2::new(0.0, 1.0));
+        let vertices = [
+            // A square over columns and rows 16 to 48, three units away
+            line_point(-1.5, -1.5, -3.0, red),
+            line_point(1.5, -1.5, -3.0, red),
+            line_point(1.5, 1.5, -3.0, red),
+            line_point(-1.5, 1.5, -3.0, red),
+            // Across row 28 behind the square, and row 36 in front of it,
+            // both from x = 6.4 to 57.6 on screen
+            line_point(-4.8, 0.65625, -6.0, red),
+            line_point(4.8, 0.65625, -6.0, red),
+            line_point(-1.6, -0.28125, -2.0, green),
+            line_point(1.6, -0.28125, -2.0, green),
+        ];
+
+        let mut gpu = cleared_gpu(SIZE, SIZE);
+        let mut pipeline = Pipeline::<2, 2, 2>::new();
+        pipeline.render_scene(
+            &vertices,
+            &[IndexedTriangle(0, 2, 1), IndexedTriangle(0, 3, 2)],
+            Shaders::new(&Project, &White),
+            &(),
+            gpu.render_target(DepthState::default()),
+        );
+        pipeline.render_lines(
+            &vertices,
+            &[TriangleEdge(4, 5), TriangleEdge(6, 7)],
+            Shaders::new(&Project, &UvColor),
+            &(),
+            gpu.render_target(DepthState::default()),
+        );
+
+        let white = Color::new(255, 255, 255);
+        for x in 7..57 {
+            let behind = match x {
+                16..48 => white,
+                _ => Color::new(255, 0, 0),
+            };
+            assert_eq!(gpu.color_buffer.get(x, 28), behind, "({x}, 28)");
+            assert_eq!(
+                gpu.color_buffer.get(x, 36),
+                Color::new(0, 255, 0),
+                "({x}, 36)"
+            );
+        }
+    }
+
+    // Renders triangles on a floor just below the camera with perspective
+    // correct uvs, keeping the uvs of every pixel shaded
+    fn render_floor_triangles(vertices: &[RawPoint<2>]) -> (Gpu, Vec<Vector2<f32>>) {
+        let shader = UvDerivatives::default();
+        let mut gpu = cleared_gpu(SIZE, SIZE);
+
+        Pipeline::<2, 2, 2>::new().render_scene(
+            vertices,
+            Primitives::new(Topology::TriangleList, Indices::None),
+            Shaders::new(&Project, &shader),
+            &(),
+            gpu.render_target(DepthState::default()),
+        );
+
+        let uvs = shader
+            .0
+            .into_inner()
+            .into_iter()
+            .map(|(uv, _)| uv)
+            .collect();
+        (gpu, uvs)
+    }
+
+    #[test]
+    fn triangles_are_clipped_at_the_near_plane() {
+        let point = |x: f32, z: f32, u: f32, v: f32| RawPoint {
+            position: Point3::new(x, -0.2, z),
+            normal: Vector3::y(),
+            parameters: Vector2::new(u, v),
+        };
+
+        // One triangle reaches from in front of the camera to behind it,
+        // and the other one is fully behind it. Unclipped, the vertices
+        // behind the camera would land upside down on the top half of
+        // the screen.
+        let (clipped, uvs) = render_floor_triangles(&[
+            point(-1.0, -3.0, 0.0, 0.0),
+            point(1.0, -3.0, 1.0, 0.0),
+            point(0.0, 1.0, 0.5, 1.0),
+            point(-1.0, 1.0, 0.0, 0.0),
+            point(0.0, 2.0, 0.5, 1.0),
+            point(1.0, 1.0, 1.0, 0.0),
+        ]);
+
+        // The same triangle cut by hand where it crosses the near plane,
+        // at z = -0.5, which is 5/8 of the way to the vertex behind
+        let (reference, _) = render_floor_triangles(&[
+            point(-1.0, -3.0, 0.0, 0.0),
+            point(1.0, -3.0, 1.0, 0.0),
+            point(0.375, -0.5, 0.6875, 0.625),
+            point(-1.0, -3.0, 0.0, 0.0),
+            point(0.375, -0.5, 0.6875, 0.625),
+            point(-0.375, -0.5, 0.3125, 0.625),
+        ]);
+
+        assert!(uvs
+            .iter()
+            .all(|uv| uv.iter().all(|value| value.is_finite())));
+        assert!(covered_pixels(&clipped) > 300);
+
+        // On screen the floor runs from its far edge at y = 34.1 down to
+        // where the near plane cuts it at y = 44.8, over rows 34 to 44
+        let black = Color::new(0, 0, 0);
+        for y in 0..SIZE {
+            for x in 0..SIZE {
+                let color = clipped.color_buffer.get(x, y);
+                if !(34..45).contains(&y) {
+                    assert_eq!(color, black, "({x}, {y})");
+                }
+
+                assert_eq!(color, reference.color_buffer.get(x, y), "({x}, {y})");
+                assert_eq!(
+                    clipped.z_buffer.get(x, y).is_finite(),
+                    color != black,
+                    "({x}, {y})"
+                );
+            }
+        }
+    }
+
+    // Writes every pixel white
+    struct White;
+
+    impl PixelShader<2> for White {
+        fn run(&self, _: SVector<f32, 2>) -> Color {
+            Color::new(255, 255, 255)
+        }
+    }
+
+    // Triangles given by their corners in ndc, which Project puts
+    // at (x, y) on a plane two units in front of the camera, with
+    // every three vertices making up one triangle of a list
+    fn ndc_triangles(triangles: &[[(f32, f32); 3]]) -> Vec<RawPoint<2>> {
+        triangles
+            .iter()
+            .flatten()
+            .map(|&(x, y)| RawPoint {
+                position: Point3::new(x * 2.0, y * 2.0, -2.0),
+                normal: Vector3::z(),
+                parameters: Vector2::zeros(),
+            })
+            .collect()
+    }
+
+    fn render_ndc_triangles(
+        pipeline: &mut Pipeline<2, 2, 2>,
+        (width, height): (usize, usize),
+        triangles: &[[(f32, f32); 3]],
+    ) -> Gpu {
+        let vertices = ndc_triangles(triangles);
+        let mut gpu = cleared_gpu(width, height);
+
+        pipeline.render_scene(
+            &vertices,
+            Primitives::new(Topology::TriangleList, Indices::None),
+            Shaders::new(&Project, &White),
+            &(),
+            gpu.render_target(DepthState::default()),
+        );
+
+        gpu
+    }
+
+    #[test]
+    fn triangles_past_the_guard_band_are_trimmed_to_the_screen() {
+        // Reaches five and a half screen widths past the left edge. With
+        // the default guard band it is clipped there first, and with the
+        // largest one the rasterizer trims it from the vertex itself.
+        let corners = [(-12.0, 0.3), (0.9, 0.8), (0.5, -0.9)];
+
+        // How far inside of the triangle a pixel center is, in pixels
+        let half_size = SIZE as f32 / 2.0;
+        let screen = corners.map(|(x, y)| ((x + 1.0) * half_size, (1.0 - y) * half_size));
+        let inside = |x: f32, y: f32| {
+            (0..3)
+                .map(|edge| {
+                    let (a, b) = (screen[edge], screen[(edge + 1) % 3]);
+                    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+                    ((y - a.1) * dx - (x - a.0) * dy) / dx.hypot(dy)
+                })
+                .fold(f32::INFINITY, f32::min)
+        };
+
+        for rasterizer in [Rasterizer::Scanline, Rasterizer::EdgeFunction] {
+            for tile_size in [Some(16), None] {
+                for guard_band in [1.0, DEFAULT_GUARD_BAND, f32::MAX] {
+                    let mut pipeline = Pipeline::new();
+                    pipeline.set_rasterizer(rasterizer);
+                    pipeline.set_tile_size(tile_size);
+                    pipeline.set_guard_band(guard_band);
+                    let gpu = render_ndc_triangles(&mut pipeline, (SIZE, SIZE), &[corners]);
+
+                    assert!(covered_pixels(&gpu) > 1000);
+                    for y in 0..SIZE {
+                        for x in 0..SIZE {
+                            let distance = inside(x as f32 + 0.5, y as f32 + 0.5);
+                            if distance.abs() > 0.01 {
+                                assert_eq!(
+                                    gpu.color_buffer.get(x, y) != Color::new(0, 0, 0),
+                                    distance > 0.0,
+                                    "{rasterizer:?} {tile_size:?} {guard_band} ({x}, {y})"
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn full_screen_quads_cover_the_last_row_and_column() {
+        let quad = [
+            [(-1.0, -1.0), (-1.0, 1.0), (1.0, 1.0)],
+            [(-1.0, -1.0), (1.0, 1.0), (1.0, -1.0)],
+        ];
+
+        for rasterizer in [Rasterizer::Scanline, Rasterizer::EdgeFunction] {
+            for tile_size in [Some(16), None] {
+                let mut pipeline = Pipeline::new();
+                pipeline.set_rasterizer(rasterizer);
+                pipeline.set_tile_size(tile_size);
+                let gpu = render_ndc_triangles(&mut pipeline, (60, 44), &quad);
+
+                assert_eq!(
+                    covered_pixels(&gpu),
+                    60 * 44,
+                    "{rasterizer:?} {tile_size:?}"
+                );
+                assert_eq!(gpu.color_buffer.get(59, 43), Color::new(255, 255, 255));
+            }
+        }
+    }
+
+    #[test]
+    fn barycentric_wireframes_draw_over_the_edges() {
+        let corners = [(-0.8, -0.8), (0.0, 0.8), (0.8, -0.8)];
+        let vertices = ndc_triangles(&[corners]);
+        let red = Color::new(255, 0, 0);
+        let wireframe = Wireframe::<_, 2> {
+            color: red,
+            width: 0.1,
+            ..Wireframe::new(White)
+        };
+
+        let mut gpu = cleared_gpu(SIZE, SIZE);
+        Pipeline::<2, 2, 5>::new().render_scene(
+            &vertices,
+            Primitives::new(Topology::TriangleList, Indices::None),
+            Shaders::new(&Project, &wireframe).with_geometry(&Barycentric),
+            &(),
+            gpu.render_target(DepthState::default()),
+        );
+
+        // The smallest barycentric coordinate of a point on the screen,
+        // which is negative outside of the triangle
+        let half_size = SIZE as f32 / 2.0;
+        let screen =
+            corners.map(|(x, y)| Vector2::new((x + 1.0) * half_size, (1.0 - y) * half_size));
+        let area = |a: Vector2<f32>, b: Vector2<f32>, c: Vector2<f32>| (b - a).perp(&(c - a));
+        let closest_edge = |point: Vector2<f32>| {
+            (0..3)
+                .map(|corner| {
+                    let (b, c) = (screen[(corner + 1) % 3], screen[(corner + 2) % 3]);
+                    area(point, b, c) / area(screen[corner], b, c)
+                })
+                .fold(f32::INFINITY, f32::min)
+        };
+
+        let (mut edges, mut inside) = (0, 0);
+        for y in 0..SIZE {
+            for x in 0..SIZE {
+                let distance = closest_edge(Vector2::new(x as f32 + 0.5, y as f32 + 0.5));
+                let expected = match distance {
+                    distance if distance.abs() < 0.02 || (distance - 0.1).abs() < 0.02 => continue,
+                    distance if distance < 0.0 => Color::new(0, 0, 0),
+                    distance if distance < 0.1 => {
+                        edges += 1;
+                        red
+                    }
+                    _ => {
+                        inside += 1;
+                        Color::new(255, 255, 255)
+                    }
+                };
+                assert_eq!(gpu.color_buffer.get(x, y), expected, "({x}, {y})");
+            }
+        }
+        assert!(
+            edges > 150 && inside > 300,
+            "{edges} edge and {inside} inside pixels"
+        );
+    }
+
+    // Adds one to the red channel of every pixel it is drawn on,
+    // when blended additively
+    struct Count;
+
+    impl PixelShader<2> for Count {
+        fn run(&self, _: SVector<f32, 2>) -> Color {
+            Color::new(1, 0, 0)
+        }
+    }
+
+    #[test]
+    fn shared_edges_are_drawn_once() {
+        // A fan of eight triangles around the middle of a rectangle. Every
+        // vertex is on a pixel center, so the horizontal, vertical and
+        // diagonal edges between them run through rows of pixel centers.
+        let ndc = |x: f32, y: f32| (x / 32.0 - 1.0, 1.0 - y / 32.0);
+        let (left, right, top, bottom) = (8.5, 56.5, 6.5, 54.5);
+        let middle = ndc(32.5, 30.5);
+        let outline = [
+            ndc(left, top),
+            ndc(32.5, top),
+            ndc(right, top),
+            ndc(right, 30.5),
+            ndc(right, bottom),
+            ndc(32.5, bottom),
+            ndc(left, bottom),
+            ndc(left, 30.5),
+        ];
+        let fan = (0..outline.len())
+            .map(|index| [middle, outline[index], outline[(index + 1) % outline.len()]])
+            .collect::<Vec<_>>();
+        let vertices = ndc_triangles(&fan);
+
+        for rasterizer in [Rasterizer::Scanline, Rasterizer::EdgeFunction] {
+            let mut gpu = cleared_gpu(SIZE, SIZE);
+
+            let mut pipeline = Pipeline::<2, 2, 2>::new();
+            pipeline.set_rasterizer(rasterizer);
+            pipeline.render_transparent_scene(
+                &vertices,
+                Primitives::new(Topology::TriangleList, Indices::None),
+                Shaders::new(&Project, &Count),
+                &(),
+                gpu.render_target(DepthState::default())
+                    .with_blend_mode(BlendMode::Additive),
+            );
+
+            // The pixels on the rectangle's left and top edges are drawn,
+            // and the ones on its right and bottom edges are left out
+            for y in 0..SIZE {
+                for x in 0..SIZE {
+                    let inside = (8..56).contains(&x) && (6..54).contains(&y);
+                    assert_eq!(
+                        gpu.color_buffer.get(x, y).r,
+                        inside as u8,
+                        "{rasterizer:?} ({x}, {y})"
+                    );
+                }
+            }
+        }
+    }
+
+    // Writes the uvs on the sprite out as red and green
+    struct SpriteUv;
+
+    impl PixelShader<4> for SpriteUv {
+        fn run(&self, params: SVector<f32, 4>) -> Color {
+            UvColor.run(Vector2::new(params.z, params.w))
+        }
+    }
+
+    fn render_points(points: &[Point3<f32>], size: PointSize) -> Gpu {
+        let mut gpu = cleared_gpu(SIZE, SIZE);
+        let vertices = points
+            .iter()
+            .map(|position| RawPoint {
+                position: *position,
+                normal: Vector3::z(),
+                parameters: Vector2::zeros(),
+            })
+            .collect::<Vec<_>>();
+
+        Pipeline::<2, 2, 4>::new().render_points(
+            &vertices,
+            Shaders::new(&Project, &SpriteUv),
+            &(),
+            &FrameUniforms {
+                view: Transform3::identity(),
+                projection: projection(),
+            },
+            &PointSprites::new(size),
+            gpu.render_target(DepthState::default()),
+        );
+
+        gpu
+    }
+
+    #[test]
+    fn points_are_sized_in_pixels() {
+        let gpu = render_points(&[Point3::new(0.0, 0.0, -2.0)], PointSize::Pixels(4.0));
+
+        // Centered on the middle of the screen, with uvs from the top left
+        assert_eq!(covered_pixels(&gpu), 16);
+        assert_eq!(gpu.color_buffer.get(30, 30), Color::new(32, 32, 0));
+        assert_eq!(gpu.color_buffer.get(33, 33), Color::new(223, 223, 0));
+    }
+
+    #[test]
+    fn world_sized_points_shrink_with_distance() {
+        let size = PointSize::World(1.0);
+
+        // One unit across is 16 pixels at a distance of 2, and 8 at 4
+        let near = render_points(&[Point3::new(-1.0, 0.0, -2.0)], size);
+        let far = render_points(&[Point3::new(1.0, 0.0, -4.0)], size);
+        assert_eq!(covered_pixels(&near), 16 * 16);
+        assert_eq!(covered_pixels(&far), 8 * 8);
+
+        // Points behind the camera aren't drawn
+        let behind = render_points(&[Point3::new(0.0, 0.0, 2.0)], size);
+        assert_eq!(covered_pixels(&behind), 0);
+    }
+
+    #[test]
+    fn strips_and_fans_match_lists() {
+        // The floor's corners are near left, near right, far right and far left
+        let strip = Primitives::new(Topology::TriangleStrip, Indices::U16(&[0, 3, 1, 2]));
+        let fan = Primitives::new(Topology::TriangleFan, Indices::U32(&[0, 3, 2, 1]));
+
+        for primitives in [strip, fan] {
+            let gpu = render_primitives(
+                Rasterizer::default(),
+                Interpolation::Perspective,
+                primitives,
+                &DefaultGeometryShader,
+                &UvColor,
+            );
+
+            let error = max_uv_error(&gpu);
+            assert!(error < 0.01, "{:?} uv error {error}", primitives.topology);
+        }
+    }
+
+    #[test]
+    fn children_are_drawn_with_their_parents_transform() {
+        // A square half a unit across, facing the camera
+        let vertices =
+            [(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)].map(|(x, y)| RawPoint {
+                position: Point3::new(x * 0.25, y * 0.25, 0.0),
+                normal: Vector3::z(),
+                parameters: Vector2::zeros(),
+            });
+        let mesh = Rc::new(Mesh::new(
+            Box::new(vertices),
+            [IndexedTriangle(0, 2, 1), IndexedTriangle(0, 3, 2)],
+        ));
+        let transform = |x: f32, z: f32, scale: f32| {
+            Transform3::from_matrix_unchecked(
+                Matrix4::new_translation(&Vector3::new(x, 0.0, z)) * Matrix4::new_scaling(scale),
+            )
+        };
+
+        // The parent doubles the square, and its scale also applies to how
+        // far the child is moved, putting the child at x = 1 in the world
+        let parent = Node::new(
+            Some(Model::new(mesh.clone(), Rc::new(White))),
+            transform(-1.0, -4.0, 2.0),
+        );
+        let child = Node::new(
+            Some(Model::new(mesh, Rc::new(Count))),
+            transform(1.0, 0.0, 0.5),
+        );
+        let scene = Scene {
+            nodes: vec![parent.with_child(child)],
+        };
+
+        let mut gpu = cleared_gpu(SIZE, SIZE);
+        Pipeline::<2, 2, 2>::new().render_scene_graph(
+            &scene,
+            &DefaultVertexShader,
+            &DefaultGeometryShader,
+            &FrameUniforms {
+                view: Transform3::identity(),
+                projection: projection(),
+            },
+            gpu.render_target(DepthState::default()),
+        );
+
+        // Four units away, each unit is 8 pixels across
+        for y in 0..SIZE {
+            for x in 0..SIZE {
+                let expected = match (x, y) {
+                    (20..28, 28..36) => Color::new(255, 255, 255),
+                    (38..42, 30..34) => Color::new(1, 0, 0),
+                    _ => Color::new(0, 0, 0),
+                };
+                assert_eq!(gpu.color_buffer.get(x, y), expected, "({x}, {y})");
+            }
+        }
+    }
 }
@@ -0,0 +1,96 @@
+use nalgebra::{Perspective3, SVector, Vector4};
+
+use crate::{
+    blend::BlendMode,
+    types::{Triangle, TriangleVertex},
+};
+
+// How large each point is drawn
+#[derive(Clone, Copy, Debug)]
+pub enum PointSize {
+    // The same number of pixels across at any distance, for stars and ui
+    Pixels(f32),
+    // A width in world units, which gets smaller further from the camera
+    // like a quad facing it would. How large a world unit is on screen
+    // comes from the frame's projection when the points are drawn.
+    World(f32),
+}
+
+// How render_points draws each point
+#[derive(Clone, Copy, Debug)]
+pub struct PointSprites {
+    pub size: PointSize,
+    // Anything but Opaque also turns off depth writes, the same as
+    // for transparent scenes, but points aren't sorted
+    pub blend_mode: BlendMode,
+}
+
+impl PointSprites {
+    pub fn new(size: PointSize) -> Self {
+        Self {
+            size,
+            blend_mode: BlendMode::Opaque,
+        }
+    }
+
+    // Expands a point in clip space into a screen aligned quad. The point's
+    // parameters are copied to every corner, followed by the corner's uvs
+    // on the sprite, from (0, 0) in the top left to (1, 1) in the bottom
+    // right. Points with their center outside of the depth range are
+    // dropped whole, like on other gpus. The projection should be the one
+    // the vertex shader used.
+    pub fn expand<const GSIN: usize, const PSIN: usize>(
+        &self,
+        point: &TriangleVertex<GSIN>,
+        (screen_width, screen_height): (usize, usize),
+        projection: &Perspective3<f32>,
+        output: &mut Vec<Triangle<PSIN>>,
+    ) {
+        const { assert!(PSIN == GSIN + 2) };
+
+        let position = point.position;
+        if position.z < -position.w || position.z > position.w {
+            return;
+        }
+
+        // Half of the size in clip space, where the screen is 2w across
+        let (half_width, half_height) = match self.size {
+            PointSize::Pixels(size) => (
+                size / screen_width as f32 * position.w,
+                size / screen_height as f32 * position.w,
+            ),
+            PointSize::World(size) => {
+                let matrix = projection.as_matrix();
+                (size * 0.5 * matrix.m11, size * 0.5 * matrix.m22)
+            }
+        };
+
+        let corner = |x: f32, y: f32| {
+            let mut parameters = SVector::<f32, PSIN>::zeros();
+            parameters
+                .fixed_rows_mut::<GSIN>(0)
+                .copy_from(&point.parameters);
+            parameters[GSIN] = (x + 1.0) * 0.5;
+            parameters[GSIN + 1] = (1.0 - y) * 0.5;
+
+            TriangleVertex {
+                position: position + Vector4::new(x * half_width, y * half_height, 0.0, 0.0),
+                parameters,
+            }
+        };
+
+        let top_left = corner(-1.0, 1.0);
+        let top_right = corner(1.0, 1.0);
+        let bottom_left = corner(-1.0, -1.0);
+        let bottom_right = corner(1.0, -1.0);
+
+        output.extend([
+            Triangle {
+                vertices: [top_left, top_right.clone(), bottom_left.clone()],
+            },
+            Triangle {
+                vertices: [top_right, bottom_right, bottom_left],
+            },
+        ]);
+    }
+}
@@ -0,0 +1,290 @@
+use crate::{texture::Texture, types::Color};
+
+// What a sampler reads for uvs outside of 0 to 1, on one axis
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum AddressMode {
+    // Tiles the texture
+    Repeat,
+    // Stretches the texels along the edge
+    #[default]
+    ClampToEdge,
+    // Tiles the texture, flipping every other copy so the edges line up
+    MirroredRepeat,
+    // Reads the sampler's border color
+    ClampToBorder,
+}
+
+impl AddressMode {
+    // The texel to read at coordinate, or none for the border color
+    fn apply(self, coordinate: i32, size: i32) -> Option<i32> {
+        match self {
+            Self::Repeat => Some(coordinate.rem_euclid(size)),
+            Self::ClampToEdge => Some(coordinate.clamp(0, size - 1)),
+            Self::MirroredRepeat => {
+                let coordinate = coordinate.rem_euclid(size * 2);
+                match coordinate < size {
+                    true => Some(coordinate),
+                    false => Some(size * 2 - 1 - coordinate),
+                }
+            }
+            Self::ClampToBorder => (0..size).contains(&coordinate).then_some(coordinate),
+        }
+    }
+}
+
+// How texels are combined when the uvs fall between them
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum Filter {
+    // The closest texel, for a blocky look
+    #[default]
+    Nearest,
+    // A blend of the four closest texels
+    Bilinear,
+}
+
+// How samplers choose between the mip levels of a texture
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum MipmapMode {
+    // Always reads the full size texture
+    #[default]
+    None,
+    // Reads the closest mip level
+    Nearest,
+    // Blends between the two closest mip levels,
+    // which with bilinear filtering is trilinear
+    Linear,
+}
+
+// Reads colors from textures at uv coordinates, with (0, 0) in the top
+// left corner of the texture and (1, 1) in the bottom right
+#[derive(Clone, Copy, Debug)]
+pub struct Sampler {
+    pub address_u: AddressMode,
+    pub address_v: AddressMode,
+    pub filter: Filter,
+    pub mipmap_mode: MipmapMode,
+    // Read outside of the texture with ClampToBorder
+    pub border_color: Color,
+}
+
+impl Default for Sampler {
+    fn default() -> Self {
+        Self::new(AddressMode::default(), Filter::default())
+    }
+}
+
+impl Sampler {
+    // Uses the same address mode on both axes
+    pub fn new(address_mode: AddressMode, filter: Filter) -> Self {
+        Self {
+            address_u: address_mode,
+            address_v: address_mode,
+            filter,
+            mipmap_mode: MipmapMode::None,
+            border_color: Color::rgba(0, 0, 0, 0),
+        }
+    }
+
+    pub fn sample(&self, texture: &Texture, u: f32, v: f32) -> Color {
+        // Texel centers are at half coordinates
+        let x = u * texture.width() as f32;
+        let y = v * texture.height() as f32;
+
+        match self.filter {
+            Filter::Nearest => self.texel(texture, x.floor() as i32, y.floor() as i32),
+            Filter::Bilinear => {
+                let (x, y) = (x - 0.5, y - 0.5);
+                let (left, top) = (x.floor(), y.floor());
+                let (tx, ty) = (x - left, y - top);
+                // Huge uvs saturate when converted, so the next
+                // texel over mustn't overflow past them
+                let (left, top) = (left as i32, top as i32);
+                let (right, bottom) = (left.saturating_add(1), top.saturating_add(1));
+
+                let top_row = lerp(
+                    self.texel(texture, left, top),
+                    self.texel(texture, right, top),
+                    tx,
+                );
+                let bottom_row = lerp(
+                    self.texel(texture, left, bottom),
+                    self.texel(texture, right, bottom),
+                    tx,
+                );
+
+                lerp(top_row, bottom_row, ty)
+            }
+        }
+    }
+
+    // Samples the texture at a level of detail from Texture::lod,
+    // going by the mipmap mode. Only reads the full size texture
+    // when it is magnified, or doesn't have mipmaps.
+    pub fn sample_lod(&self, texture: &Texture, u: f32, v: f32, lod: f32) -> Color {
+        // Degenerate derivatives can give an infinite or NaN lod,
+        // which max and min both turn into a level that exists
+        let max_level = (texture.mip_levels() - 1) as f32;
+        let lod = lod.max(0.0).min(max_level);
+
+        match self.mipmap_mode {
+            MipmapMode::None => self.sample(texture, u, v),
+            MipmapMode::Nearest => self.sample(texture.level(lod.round() as usize), u, v),
+            MipmapMode::Linear => {
+                let level = lod.floor();
+                lerp(
+                    self.sample(texture.level(level as usize), u, v),
+                    self.sample(texture.level(level as usize + 1), u, v),
+                    lod - level,
+                )
+            }
+        }
+    }
+
+    fn texel(&self, texture: &Texture, x: i32, y: i32) -> Color {
+        let x = self.address_u.apply(x, texture.width() as i32);
+        let y = self.address_v.apply(y, texture.height() as i32);
+
+        match (x, y) {
+            (Some(x), Some(y)) => texture.texel(x as usize, y as usize),
+            _ => self.border_color,
+        }
+    }
+}
+
+fn lerp(a: Color, b: Color, t: f32) -> Color {
+    let channel = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+
+    Color::rgba(
+        channel(a.r, b.r),
+        channel(a.g, b.g),
+        channel(a.b, b.b),
+        channel(a.a, b.a),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BLACK: Color = Color::new(0, 0, 0);
+    const WHITE: Color = Color::new(255, 255, 255);
+
+    // Black on the left half, white on the right
+    fn halves() -> Texture {
+        Texture::new(2, 1, [BLACK, WHITE])
+    }
+
+    #[test]
+    fn address_modes_wrap_each_axis() {
+        let texture = halves();
+        let sample = |address_u, u| {
+            let sampler = Sampler {
+                address_u,
+                ..Sampler::new(AddressMode::ClampToBorder, Filter::Nearest)
+            };
+            sampler.sample(&texture, u, 0.5)
+        };
+
+        assert_eq!(sample(AddressMode::Repeat, 1.25), BLACK);
+        assert_eq!(sample(AddressMode::Repeat, -0.25), WHITE);
+        assert_eq!(sample(AddressMode::ClampToEdge, 3.0), WHITE);
+        assert_eq!(sample(AddressMode::MirroredRepeat, 1.25), WHITE);
+        assert_eq!(sample(AddressMode::MirroredRepeat, 1.75), BLACK);
+        assert_eq!(
+            sample(AddressMode::ClampToBorder, 1.25),
+            Color::rgba(0, 0, 0, 0)
+        );
+        assert_eq!(sample(AddressMode::ClampToBorder, 0.75), WHITE);
+
+        // The address mode on v is separate
+        let sampler = Sampler::new(AddressMode::ClampToBorder, Filter::Nearest);
+        assert_eq!(
+            sampler.sample(&texture, 0.25, -0.5),
+            Color::rgba(0, 0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn bilinear_filtering_blends_between_texel_centers() {
+        let texture = halves();
+        let sampler = Sampler::new(AddressMode::ClampToEdge, Filter::Bilinear);
+
+        assert_eq!(sampler.sample(&texture, 0.25, 0.5), BLACK);
+        assert_eq!(
+            sampler.sample(&texture, 0.5, 0.5),
+            Color::new(128, 128, 128)
+        );
+        assert_eq!(sampler.sample(&texture, 0.75, 0.5), WHITE);
+        assert_eq!(sampler.sample(&texture, 1.0, 0.5), WHITE);
+
+        // Wrapping blends the right edge back into the left
+        let sampler = Sampler::new(AddressMode::Repeat, Filter::Bilinear);
+        assert_eq!(
+            sampler.sample(&texture, 1.0, 0.5),
+            Color::new(128, 128, 128)
+        );
+    }
+
+    #[test]
+    fn huge_uvs_do_not_overflow() {
+        let texture = halves();
+
+        for address_mode in [
+            AddressMode::Repeat,
+            AddressMode::ClampToEdge,
+            AddressMode::MirroredRepeat,
+            AddressMode::ClampToBorder,
+        ] {
+            for filter in [Filter::Nearest, Filter::Bilinear] {
+                let sampler = Sampler::new(address_mode, filter);
+                for u in [1e10, -1e10, f32::INFINITY, f32::NAN] {
+                    sampler.sample(&texture, u, u);
+                }
+            }
+        }
+
+        // Clamping still reads the closest edge
+        let sampler = Sampler::new(AddressMode::ClampToEdge, Filter::Bilinear);
+        assert_eq!(sampler.sample(&texture, 1e10, 0.5), WHITE);
+        assert_eq!(sampler.sample(&texture, -1e10, 0.5), BLACK);
+    }
+
+    #[test]
+    fn mipmap_modes_pick_levels() {
+        // A checkerboard, which averages to gray in the smaller level
+        let texture = Texture::checkerboard(2, 2, 1, WHITE, BLACK).with_mipmaps();
+        let gray = Color::new(128, 128, 128);
+        let sample = |mipmap_mode, lod| {
+            let sampler = Sampler {
+                mipmap_mode,
+                ..Sampler::default()
+            };
+            sampler.sample_lod(&texture, 0.25, 0.25, lod)
+        };
+
+        assert_eq!(sample(MipmapMode::None, 1.0), WHITE);
+        assert_eq!(sample(MipmapMode::Nearest, -2.0), WHITE);
+        assert_eq!(sample(MipmapMode::Nearest, 0.4), WHITE);
+        assert_eq!(sample(MipmapMode::Nearest, 0.6), gray);
+        assert_eq!(sample(MipmapMode::Linear, 0.5), Color::new(192, 192, 192));
+        assert_eq!(sample(MipmapMode::Linear, 3.0), gray);
+    }
+
+    #[test]
+    fn degenerate_lods_are_clamped() {
+        let texture = Texture::checkerboard(2, 2, 1, WHITE, BLACK).with_mipmaps();
+        let gray = Color::new(128, 128, 128);
+
+        for mipmap_mode in [MipmapMode::Nearest, MipmapMode::Linear] {
+            let sampler = Sampler {
+                mipmap_mode,
+                ..Sampler::default()
+            };
+            let sample = |lod| sampler.sample_lod(&texture, 0.25, 0.25, lod);
+
+            assert_eq!(sample(f32::NAN), WHITE, "{mipmap_mode:?}");
+            assert_eq!(sample(f32::NEG_INFINITY), WHITE, "{mipmap_mode:?}");
+            assert_eq!(sample(f32::INFINITY), gray, "{mipmap_mode:?}");
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use std::rc::Rc;
+
+use nalgebra::Transform3;
+
+use crate::{
+    shaders::PixelShader,
+    topology::{IndexBuffer, Primitives, Topology},
+    types::RawPoint,
+};
+
+// Vertex and index data which can be shared between nodes
+pub struct Mesh<const D: usize> {
+    pub vertices: Box<[RawPoint<D>]>,
+    pub indices: IndexBuffer,
+    pub topology: Topology,
+}
+
+impl<const D: usize> Mesh<D> {
+    // Takes the indices of a triangle list,
+    // use with_topology for strips and fans
+    pub fn new(vertices: Box<[RawPoint<D>]>, indices: impl Into<IndexBuffer>) -> Self {
+        Self {
+            vertices,
+            indices: indices.into(),
+            topology: Topology::TriangleList,
+        }
+    }
+
+    pub fn with_topology(mut self, topology: Topology) -> Self {
+        self.topology = topology;
+        self
+    }
+
+    pub fn primitives(&self) -> Primitives<'_> {
+        Primitives::new(self.topology, self.indices.as_indices())
+    }
+}
+
+// A mesh, and the pixel shader it is drawn with. The material reads
+// PSIN parameters, which is more than the mesh has when the vertex
+// shader adds some of its own.
+#[derive(Clone)]
+pub struct Model<const D: usize, const PSIN: usize = D> {
+    pub mesh: Rc<Mesh<D>>,
+    pub material: Rc<dyn PixelShader<PSIN>>,
+}
+
+impl<const D: usize, const PSIN: usize> Model<D, PSIN> {
+    pub fn new(mesh: Rc<Mesh<D>>, material: Rc<dyn PixelShader<PSIN>>) -> Self {
+        Self { mesh, material }
+    }
+}
+
+// An entry in the scene graph. The transform is relative to the
+// parent node, so moving a node moves all of its children with it.
+// Nodes without a model only group their children.
+pub struct Node<const D: usize, const PSIN: usize = D> {
+    pub model: Option<Model<D, PSIN>>,
+    pub transform: Transform3<f32>,
+    pub children: Vec<Node<D, PSIN>>,
+}
+
+impl<const D: usize, const PSIN: usize> Node<D, PSIN> {
+    pub fn new(model: Option<Model<D, PSIN>>, transform: Transform3<f32>) -> Self {
+        Self {
+            model,
+            transform,
+            children: Vec::new(),
+        }
+    }
+
+    pub fn with_child(mut self, child: Node<D, PSIN>) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    // Calls f with every model under this node, and the transform
+    // from its model space to world space
+    pub fn visit(
+        &self,
+        parent: &Transform3<f32>,
+        f: &mut impl FnMut(&Model<D, PSIN>, &Transform3<f32>),
+    ) {
+        let world = parent * self.transform;
+
+        if let Some(model) = &self.model {
+            f(model, &world);
+        }
+
+        self.children
+            .iter()
+            .for_each(|child| child.visit(&world, f));
+    }
+}
+
+// The root nodes of everything drawn in a frame
+#[derive(Default)]
+pub struct Scene<const D: usize, const PSIN: usize = D> {
+    pub nodes: Vec<Node<D, PSIN>>,
+}
+
+impl<const D: usize, const PSIN: usize> Scene<D, PSIN> {
+    pub fn visit(&self, mut f: impl FnMut(&Model<D, PSIN>, &Transform3<f32>)) {
+        let identity = Transform3::identity();
+        self.nodes
+            .iter()
+            .for_each(|node| node.visit(&identity, &mut f));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_PI_2;
+
+    use nalgebra::{Point3, Rotation3, Translation3, Vector3};
+
+    use super::*;
+    use crate::shaders::ColorBlend;
+
+    fn model() -> Model<3> {
+        let mesh = Mesh::new(Box::new([]), Box::<[u16]>::default());
+        Model::new(Rc::new(mesh), Rc::new(ColorBlend::default()))
+    }
+
+    #[test]
+    fn children_are_moved_with_their_parents() {
+        let (parent, child) = (model(), model());
+        let translation = Transform3::identity() * Translation3::new(1.0, 2.0, 3.0);
+        let rotation =
+            Transform3::identity() * Rotation3::from_axis_angle(&Vector3::z_axis(), FRAC_PI_2);
+
+        let scene = Scene {
+            nodes: vec![Node::new(Some(parent.clone()), translation)
+                .with_child(Node::new(Some(child.clone()), rotation))],
+        };
+
+        let mut visited = Vec::new();
+        scene.visit(|model, world| visited.push((model.mesh.clone(), *world)));
+
+        // Parents come before their children
+        assert_eq!(visited.len(), 2);
+        assert!(Rc::ptr_eq(&visited[0].0, &parent.mesh));
+        assert!(Rc::ptr_eq(&visited[1].0, &child.mesh));
+
+        assert_eq!(visited[0].1, translation);
+        assert_eq!(visited[1].1, translation * rotation);
+
+        // The child turns x onto y, and is then moved along with its parent
+        let moved = visited[1].1 * Point3::new(1.0, 0.0, 0.0);
+        assert!(
+            (moved - Point3::new(1.0, 3.0, 3.0)).norm() < 1e-6,
+            "{moved}"
+        );
+    }
+}
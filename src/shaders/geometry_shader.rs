@@ -1,14 +1,239 @@
-use crate::types::Triangle;
+use nalgebra::{SVector, Vector3};
 
-// Processes triangles
+use crate::types::{Triangle, TriangleVertex};
+
+use super::LIT_PARAMETERS;
+
+// Processes triangles, pushing any number of them into the output,
+// including none to discard the triangle. The primitive id counts
+// the triangles of each draw, in the order of the indices.
 pub trait GeometryShader<const GSIN: usize, const GSOUT: usize> {
-    fn run(triangle: Triangle<GSIN>) -> Triangle<GSOUT>;
+    fn run(&self, triangle: Triangle<GSIN>, primitive_id: usize, output: &mut Vec<Triangle<GSOUT>>);
 }
 
 pub struct DefaultGeometryShader;
 
 impl<const GS: usize> GeometryShader<GS, GS> for DefaultGeometryShader {
-    fn run(triangle: Triangle<GS>) -> Triangle<GS> {
-        triangle
+    fn run(&self, triangle: Triangle<GS>, _primitive_id: usize, output: &mut Vec<Triangle<GS>>) {
+        output.push(triangle);
+    }
+}
+
+// Replaces the normals from the LitVertexShader with the normal of the
+// whole face, so each triangle is lit evenly. D is the material's own
+// parameter count, the same as for Lit pixel shaders.
+pub struct FlatNormals<const D: usize>;
+
+impl<const D: usize, const GS: usize> GeometryShader<GS, GS> for FlatNormals<D> {
+    fn run(
+        &self,
+        mut triangle: Triangle<GS>,
+        _primitive_id: usize,
+        output: &mut Vec<Triangle<GS>>,
+    ) {
+        const { assert!(GS == D + LIT_PARAMETERS) };
+
+        let position = |vertex: &TriangleVertex<GS>| -> Vector3<f32> {
+            vertex.parameters.fixed_rows::<3>(D).into_owned()
+        };
+        let normal = |vertex: &TriangleVertex<GS>| -> Vector3<f32> {
+            vertex.parameters.fixed_rows::<3>(D + 3).into_owned()
+        };
+
+        let [a, b, c] = &triangle.vertices;
+        let face_normal = (position(b) - position(a)).cross(&(position(c) - position(a)));
+
+        // Which way the cross product points depends on the winding,
+        // so it is turned to agree with the smooth normals
+        let smooth_normal = normal(a) + normal(b) + normal(c);
+        let face_normal = match face_normal.try_normalize(f32::EPSILON) {
+            Some(face_normal) if face_normal.dot(&smooth_normal) < 0.0 => -face_normal,
+            Some(face_normal) => face_normal,
+            None => return output.push(triangle),
+        };
+
+        triangle.vertices.iter_mut().for_each(|vertex| {
+            vertex
+                .parameters
+                .fixed_rows_mut::<3>(D + 3)
+                .copy_from(&face_normal)
+        });
+        output.push(triangle);
+    }
+}
+
+// Replaces the parameters with a single one holding an id for the
+// triangle, for use with the IdColor pixel shader. Draws of different
+// objects should start at different ids to tell them apart.
+pub struct TriangleId {
+    pub first_id: usize,
+}
+
+impl<const GSIN: usize> GeometryShader<GSIN, 1> for TriangleId {
+    fn run(&self, triangle: Triangle<GSIN>, primitive_id: usize, output: &mut Vec<Triangle<1>>) {
+        let id = SVector::from([(self.first_id + primitive_id) as f32]);
+
+        output.push(Triangle {
+            vertices: triangle.vertices.map(|vertex| TriangleVertex {
+                position: vertex.position,
+                parameters: id,
+            }),
+        });
+    }
+}
+
+// Appends the barycentric coordinates of each vertex to its parameters,
+// so pixel shaders can tell how close they are to the triangle's edges.
+// GSOUT must be GSIN + 3.
+pub struct Barycentric;
+
+impl<const GSIN: usize, const GSOUT: usize> GeometryShader<GSIN, GSOUT> for Barycentric {
+    fn run(
+        &self,
+        triangle: Triangle<GSIN>,
+        _primitive_id: usize,
+        output: &mut Vec<Triangle<GSOUT>>,
+    ) {
+        const { assert!(GSOUT == GSIN + 3) };
+
+        let mut corner = 0;
+        output.push(Triangle {
+            vertices: triangle.vertices.map(|vertex| {
+                let mut parameters = SVector::<f32, GSOUT>::zeros();
+                parameters
+                    .fixed_rows_mut::<GSIN>(0)
+                    .copy_from(&vertex.parameters);
+                parameters[GSIN + corner] = 1.0;
+                corner += 1;
+
+                TriangleVertex {
+                    position: vertex.position,
+                    parameters,
+                }
+            }),
+        });
+    }
+}
+
+// Splits each triangle into four, through the middle of each edge.
+// Nothing changes on its own, but it gives later stages more vertices
+// to work with. Positions are still in clip space, so the new vertices
+// stay on the same plane as the original triangle.
+pub struct Subdivide;
+
+impl<const GS: usize> GeometryShader<GS, GS> for Subdivide {
+    fn run(&self, triangle: Triangle<GS>, _primitive_id: usize, output: &mut Vec<Triangle<GS>>) {
+        let [a, b, c] = triangle.vertices;
+        let ab = a.lerp(&b, 0.5);
+        let bc = b.lerp(&c, 0.5);
+        let ca = c.lerp(&a, 0.5);
+
+        // Each keeps the winding of the original
+        output.extend([
+            Triangle {
+                vertices: [a, ab.clone(), ca.clone()],
+            },
+            Triangle {
+                vertices: [ab.clone(), b, bc.clone()],
+            },
+            Triangle {
+                vertices: [ca.clone(), bc.clone(), c],
+            },
+            Triangle {
+                vertices: [ab, bc, ca],
+            },
+        ]);
+    }
+}
+
+// Only passes on the triangles which the function returns true for,
+// which is called with each triangle and its primitive id
+pub struct TriangleFilter<F>(pub F);
+
+impl<const GS: usize, F: Fn(&Triangle<GS>, usize) -> bool> GeometryShader<GS, GS>
+    for TriangleFilter<F>
+{
+    fn run(&self, triangle: Triangle<GS>, primitive_id: usize, output: &mut Vec<Triangle<GS>>) {
+        if (self.0)(&triangle, primitive_id) {
+            output.push(triangle);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::{Vector1, Vector4};
+
+    use super::*;
+
+    // A lit vertex with one material parameter, at a position with a smooth normal
+    fn lit_vertex(position: [f32; 3], normal: [f32; 3]) -> TriangleVertex<10> {
+        let mut parameters = SVector::<f32, 10>::zeros();
+        parameters[0] = 0.5;
+        parameters.fixed_rows_mut::<3>(1).copy_from_slice(&position);
+        parameters.fixed_rows_mut::<3>(4).copy_from_slice(&normal);
+
+        TriangleVertex {
+            position: Vector4::zeros(),
+            parameters,
+        }
+    }
+
+    #[test]
+    fn flat_normals_are_the_face_normal() {
+        for (smooth_normal, face_normal) in [
+            ([0.6, 0.8, 0.0], Vector3::y()),
+            ([0.0, -0.8, 0.6], -Vector3::y()),
+        ] {
+            let triangle = Triangle {
+                vertices: [
+                    lit_vertex([0.0, 0.0, 0.0], smooth_normal),
+                    lit_vertex([1.0, 0.0, 0.0], smooth_normal),
+                    lit_vertex([0.0, 0.0, -1.0], smooth_normal),
+                ],
+            };
+
+            let mut output = Vec::new();
+            FlatNormals::<1>.run(triangle, 0, &mut output);
+
+            let [triangle] = output.as_slice() else {
+                panic!("expected one triangle");
+            };
+            for vertex in &triangle.vertices {
+                assert_eq!(vertex.parameters.fixed_rows::<3>(4), face_normal);
+                assert_eq!(vertex.parameters[0], 0.5);
+            }
+            assert_eq!(
+                triangle.vertices[1].parameters.fixed_rows::<3>(1),
+                Vector3::x()
+            );
+        }
+    }
+
+    #[test]
+    fn barycentric_coordinates_are_one_at_their_corner() {
+        let vertex = |parameter: f32| TriangleVertex {
+            position: Vector4::zeros(),
+            parameters: Vector1::new(parameter),
+        };
+        let triangle = Triangle {
+            vertices: [vertex(1.0), vertex(2.0), vertex(3.0)],
+        };
+
+        let mut output = Vec::new();
+        GeometryShader::<1, 4>::run(&Barycentric, triangle, 0, &mut output);
+
+        let parameters = output[0]
+            .vertices
+            .each_ref()
+            .map(|vertex| vertex.parameters);
+        assert_eq!(
+            parameters,
+            [
+                Vector4::new(1.0, 1.0, 0.0, 0.0),
+                Vector4::new(2.0, 0.0, 1.0, 0.0),
+                Vector4::new(3.0, 0.0, 0.0, 1.0),
+            ]
+        );
     }
 }
@@ -0,0 +1,222 @@
+use nalgebra::{Point3, Vector3};
+
+// How many parameters the lit vertex shader adds after a material's own.
+// They are the surface's world position, its normal, and the direction
+// from it to the camera, each three long.
+pub const LIT_PARAMETERS: usize = 9;
+
+#[derive(Clone, Copy, Debug)]
+pub enum Light {
+    // Lights everything from the same direction, like the sun
+    Directional {
+        direction: Vector3<f32>,
+        color: Vector3<f32>,
+    },
+    // Shines in every direction, fading out until range
+    Point {
+        position: Point3<f32>,
+        color: Vector3<f32>,
+        range: f32,
+    },
+    // A point light limited to a cone. Light fades out between
+    // the inner and outer angles, which are in radians.
+    Spot {
+        position: Point3<f32>,
+        direction: Vector3<f32>,
+        color: Vector3<f32>,
+        range: f32,
+        inner_angle: f32,
+        outer_angle: f32,
+    },
+}
+
+impl Light {
+    // Returns the direction from the surface toward the
+    // light, and how much of the light's color reaches it
+    fn incoming(&self, position: &Point3<f32>) -> (Vector3<f32>, Vector3<f32>) {
+        match *self {
+            Light::Directional { direction, color } => (-direction.normalize(), color),
+            Light::Point {
+                position: light_position,
+                color,
+                range,
+            } => {
+                let to_light = light_position - position;
+                let distance = to_light.norm();
+                (to_light / distance, color * falloff(distance, range))
+            }
+            Light::Spot {
+                position: light_position,
+                direction,
+                color,
+                range,
+                inner_angle,
+                outer_angle,
+            } => {
+                let to_light = light_position - position;
+                let distance = to_light.norm();
+                let to_light = to_light / distance;
+
+                let cos_angle = -to_light.dot(&direction.normalize());
+                let cone = smoothstep(outer_angle.cos(), inner_angle.cos(), cos_angle);
+
+                (to_light, color * falloff(distance, range) * cone)
+            }
+        }
+    }
+}
+
+// Fades smoothly to nothing at range, rather than the physically
+// correct inverse square which never quite reaches zero
+fn falloff(distance: f32, range: f32) -> f32 {
+    let ratio = (distance / range).min(1.0);
+    let falloff = 1.0 - ratio * ratio;
+    falloff * falloff
+}
+
+fn smoothstep(edge0: f32, edge1: f32, x: f32) -> f32 {
+    let t = ((x - edge0) / (edge1 - edge0)).clamp(0.0, 1.0);
+    t * t * (3.0 - 2.0 * t)
+}
+
+// The lights shared by every lit material in a scene
+#[derive(Clone, Debug)]
+pub struct Lighting {
+    // Added to every surface, so those facing away from the lights aren't black
+    pub ambient: Vector3<f32>,
+    pub lights: Vec<Light>,
+}
+
+impl Default for Lighting {
+    fn default() -> Self {
+        Self {
+            ambient: Vector3::new(0.1, 0.1, 0.1),
+            lights: Vec::new(),
+        }
+    }
+}
+
+impl Lighting {
+    // Sums the lights with the Phong model. Returns the light to multiply
+    // the surface's color by, and the specular highlight to add on top.
+    // Both vectors are expected to be normalized.
+    pub fn shade(
+        &self,
+        position: &Point3<f32>,
+        normal: &Vector3<f32>,
+        to_camera: &Vector3<f32>,
+        shininess: f32,
+    ) -> (Vector3<f32>, Vector3<f32>) {
+        self.lights.iter().fold(
+            (self.ambient, Vector3::zeros()),
+            |(diffuse, specular), light| {
+                let (to_light, color) = light.incoming(position);
+                let lambert = normal.dot(&to_light);
+
+                if lambert <= 0.0 {
+                    return (diffuse, specular);
+                }
+
+                let reflected = normal * (2.0 * lambert) - to_light;
+                let highlight = reflected.dot(to_camera).max(0.0).powf(shininess);
+
+                (diffuse + color * lambert, specular + color * highlight)
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_1_SQRT_2;
+
+    use super::*;
+
+    fn assert_close(actual: Vector3<f32>, expected: Vector3<f32>) {
+        assert!(
+            (actual - expected).norm() < 1e-5,
+            "{actual:?} != {expected:?}"
+        );
+    }
+
+    fn lighting(light: Light) -> Lighting {
+        Lighting {
+            ambient: Vector3::new(0.1, 0.1, 0.1),
+            lights: vec![light],
+        }
+    }
+
+    #[test]
+    fn directional_lights_are_diffuse_and_specular() {
+        let color = Vector3::new(1.0, 0.5, 0.0);
+        let lighting = lighting(Light::Directional {
+            direction: Vector3::new(0.0, -1.0, -1.0),
+            color,
+        });
+        let up = Vector3::y();
+        let shade =
+            |to_camera: Vector3<f32>| lighting.shade(&Point3::origin(), &up, &to_camera, 2.0);
+
+        // Coming in at 45 degrees, the reflection leaves at 45 degrees on the other side
+        let reflected = Vector3::new(0.0, 1.0, -1.0).normalize();
+        let (diffuse, specular) = shade(reflected);
+        assert_close(diffuse, lighting.ambient + color * FRAC_1_SQRT_2);
+        assert_close(specular, color);
+
+        // Looking straight down is 45 degrees off the reflection
+        let (_, specular) = shade(up);
+        assert_close(specular, color * 0.5);
+
+        // Surfaces facing away only get the ambient light
+        let (diffuse, specular) = lighting.shade(&Point3::origin(), &-up, &-up, 2.0);
+        assert_close(diffuse, lighting.ambient);
+        assert_close(specular, Vector3::zeros());
+    }
+
+    #[test]
+    fn spot_lights_are_cut_off_outside_of_the_cone() {
+        let color = Vector3::new(1.0, 1.0, 1.0);
+        let lighting = lighting(Light::Spot {
+            position: Point3::new(0.0, 2.0, 0.0),
+            direction: -Vector3::y(),
+            color,
+            range: 10.0,
+            inner_angle: 0.2,
+            outer_angle: 0.3,
+        });
+        let diffuse = |x: f32| {
+            let (diffuse, _) =
+                lighting.shade(&Point3::new(x, 0.0, 0.0), &Vector3::y(), &Vector3::y(), 8.0);
+            diffuse
+        };
+
+        // Straight below, only faded by the distance
+        let falloff = (1.0 - 0.2 * 0.2) * (1.0 - 0.2 * 0.2);
+        assert_close(diffuse(0.0), lighting.ambient + color * falloff);
+
+        // Past the outer angle there is no light at all
+        assert_close(diffuse(2.0 * 0.35f32.tan()), lighting.ambient);
+
+        // Between the angles it fades out
+        let edge = diffuse(2.0 * 0.25f32.tan()).x - lighting.ambient.x;
+        assert!(edge > 0.0 && edge < falloff * 0.9, "{edge}");
+    }
+
+    #[test]
+    fn point_lights_fade_out_at_their_range() {
+        let lighting = lighting(Light::Point {
+            position: Point3::new(0.0, 1.0, 0.0),
+            color: Vector3::new(1.0, 1.0, 1.0),
+            range: 2.0,
+        });
+        let diffuse = |y: f32| {
+            let position = Point3::new(0.0, y, 0.0);
+            lighting
+                .shade(&position, &Vector3::y(), &Vector3::y(), 8.0)
+                .0
+        };
+
+        assert_close(diffuse(0.0), lighting.ambient + Vector3::repeat(0.5625));
+        assert_close(diffuse(-1.0), lighting.ambient);
+    }
+}
@@ -1,7 +1,11 @@
 pub mod geometry_shader;
+pub mod lighting;
 pub mod pixel_shader;
+pub mod uniforms;
 pub mod vertex_shader;
 
 pub use geometry_shader::*;
+pub use lighting::*;
 pub use pixel_shader::*;
+pub use uniforms::*;
 pub use vertex_shader::*;
@@ -1,43 +1,391 @@
-use nalgebra::SVector;
+use std::rc::Rc;
+
+use nalgebra::{Point3, SVector, Vector3};
 
 use crate::{
-    image::{self, IMAGE_HEIGHT, IMAGE_WIDTH},
+    image,
+    sampler::{MipmapMode, Sampler},
+    texture::Texture,
     types::Color,
 };
 
+use super::{Lighting, LIT_PARAMETERS};
+
 // Receives PSIN input parameters and outputs a pixel color
 pub trait PixelShader<const PSIN: usize> {
-    fn run(params: SVector<f32, PSIN>) -> Color;
+    fn run(&self, params: SVector<f32, PSIN>) -> Color;
+
+    // Whether run_with_derivatives should be called for triangles. Working
+    // out the derivatives costs a little for every pixel, so shaders which
+    // don't read them are given plain run instead. This is a method rather
+    // than a const so materials can still be used as dyn PixelShader.
+    fn uses_derivatives(&self) -> bool {
+        false
+    }
+
+    // Called instead of run for triangles when uses_derivatives is true,
+    // with how quickly the parameters change across the screen. Shaders
+    // which sample textures use them to pick a mip level.
+    fn run_with_derivatives(
+        &self,
+        params: SVector<f32, PSIN>,
+        _derivatives: &Derivatives<PSIN>,
+    ) -> Color {
+        self.run(params)
+    }
 }
 
-pub struct ColorBlend;
+// How much each parameter changes from one pixel to the next one to the
+// right, and to the one below
+#[derive(Clone, Copy, Debug)]
+pub struct Derivatives<const D: usize> {
+    pub dx: SVector<f32, D>,
+    pub dy: SVector<f32, D>,
+}
 
-impl PixelShader<3> for ColorBlend {
-    fn run(shader_params: SVector<f32, 3>) -> Color {
-        Color {
-            r: (shader_params.x.clamp(0.0, 1.0) * 255.0) as u8,
-            g: (shader_params.y.clamp(0.0, 1.0) * 255.0) as u8,
-            b: (shader_params.z.clamp(0.0, 1.0) * 255.0) as u8,
+impl<const D: usize> Derivatives<D> {
+    // The derivatives of N parameters starting at offset,
+    // for passing on to another material
+    pub fn rows<const N: usize>(&self, offset: usize) -> Derivatives<N> {
+        Derivatives {
+            dx: self.dx.fixed_rows::<N>(offset).into_owned(),
+            dy: self.dy.fixed_rows::<N>(offset).into_owned(),
         }
     }
 }
 
-pub struct Textured;
+// Uses the parameters as the color, multiplied by the tint
+pub struct ColorBlend {
+    pub tint: Vector3<f32>,
+}
 
-impl Textured {
-    fn sample_2d(u: f32, v: f32) -> Color {
-        let u = (u * (IMAGE_WIDTH - 1) as f32) as usize;
-        let v = (v * (IMAGE_HEIGHT - 1) as f32) as usize;
+impl Default for ColorBlend {
+    fn default() -> Self {
+        Self {
+            tint: Vector3::new(1.0, 1.0, 1.0),
+        }
+    }
+}
+
+impl ColorBlend {
+    fn channel(&self, value: f32, channel: usize) -> u8 {
+        ((value * self.tint[channel]).clamp(0.0, 1.0) * 255.0) as u8
+    }
+}
+
+impl PixelShader<3> for ColorBlend {
+    fn run(&self, shader_params: SVector<f32, 3>) -> Color {
+        Color::new(
+            self.channel(shader_params.x, 0),
+            self.channel(shader_params.y, 1),
+            self.channel(shader_params.z, 2),
+        )
+    }
+}
+
+// Same as above, but with the fourth parameter used as alpha
+impl PixelShader<4> for ColorBlend {
+    fn run(&self, shader_params: SVector<f32, 4>) -> Color {
+        Color::rgba(
+            self.channel(shader_params.x, 0),
+            self.channel(shader_params.y, 1),
+            self.channel(shader_params.z, 2),
+            (shader_params.w.clamp(0.0, 1.0) * 255.0) as u8,
+        )
+    }
+}
+
+// Samples a texture with the parameters as uvs
+#[derive(Clone)]
+pub struct Textured {
+    pub texture: Rc<Texture>,
+    pub sampler: Sampler,
+}
+
+impl Default for Textured {
+    // Uses the built in image
+    fn default() -> Self {
+        Self::new(Rc::new(image::texture()))
+    }
+}
 
-        let u = u.clamp(0, IMAGE_WIDTH - 1);
-        let v = v.clamp(0, IMAGE_HEIGHT - 1);
+impl Textured {
+    pub fn new(texture: Rc<Texture>) -> Self {
+        Self {
+            texture,
+            sampler: Sampler::default(),
+        }
+    }
 
-        image::get_image()[(v * IMAGE_WIDTH) + u]
+    pub fn with_sampler(mut self, sampler: Sampler) -> Self {
+        self.sampler = sampler;
+        self
     }
 }
 
 impl PixelShader<2> for Textured {
-    fn run(shader_params: SVector<f32, 2>) -> Color {
-        Self::sample_2d(shader_params.x, shader_params.y)
+    fn run(&self, shader_params: SVector<f32, 2>) -> Color {
+        self.sampler
+            .sample(&self.texture, shader_params.x, shader_params.y)
+    }
+
+    // Only picking a mip level needs them, and there has to be more
+    // than one level to pick from
+    fn uses_derivatives(&self) -> bool {
+        self.sampler.mipmap_mode != MipmapMode::None && self.texture.mip_levels() > 1
+    }
+
+    fn run_with_derivatives(
+        &self,
+        shader_params: SVector<f32, 2>,
+        derivatives: &Derivatives<2>,
+    ) -> Color {
+        let lod = self.texture.lod(derivatives.dx, derivatives.dy);
+        self.sampler
+            .sample_lod(&self.texture, shader_params.x, shader_params.y, lod)
+    }
+}
+
+// Lights the color of another material, which reads the first D
+// parameters. The rest come from the LitVertexShader.
+pub struct Lit<M, const D: usize> {
+    pub material: M,
+    pub lighting: Lighting,
+    // Color of the highlights, black turns them off
+    pub specular: Vector3<f32>,
+    // Larger values give smaller, sharper highlights
+    pub shininess: f32,
+}
+
+impl<M, const D: usize> Lit<M, D> {
+    pub fn new(material: M, lighting: Lighting) -> Self {
+        Self {
+            material,
+            lighting,
+            specular: Vector3::new(0.5, 0.5, 0.5),
+            shininess: 16.0,
+        }
+    }
+
+    // Lights the material's color with the rest of the parameters
+    fn light<const PSIN: usize>(&self, color: Color, shader_params: SVector<f32, PSIN>) -> Color {
+        let position = Point3::from(shader_params.fixed_rows::<3>(D).into_owned());
+        let normal = shader_params.fixed_rows::<3>(D + 3).normalize();
+        let to_camera = shader_params.fixed_rows::<3>(D + 6).normalize();
+
+        let (diffuse, specular) =
+            self.lighting
+                .shade(&position, &normal, &to_camera, self.shininess);
+        let specular = specular.component_mul(&self.specular);
+
+        let channel = |value: u8, channel: usize| {
+            let lit = value as f32 / 255.0 * diffuse[channel] + specular[channel];
+            (lit.clamp(0.0, 1.0) * 255.0) as u8
+        };
+
+        Color::rgba(
+            channel(color.r, 0),
+            channel(color.g, 1),
+            channel(color.b, 2),
+            color.a,
+        )
+    }
+}
+
+impl<M: PixelShader<D>, const D: usize, const PSIN: usize> PixelShader<PSIN> for Lit<M, D> {
+    fn run(&self, shader_params: SVector<f32, PSIN>) -> Color {
+        const { assert!(PSIN == D + LIT_PARAMETERS) };
+
+        let color = self
+            .material
+            .run(shader_params.fixed_rows::<D>(0).into_owned());
+        self.light(color, shader_params)
+    }
+
+    fn uses_derivatives(&self) -> bool {
+        self.material.uses_derivatives()
+    }
+
+    fn run_with_derivatives(
+        &self,
+        shader_params: SVector<f32, PSIN>,
+        derivatives: &Derivatives<PSIN>,
+    ) -> Color {
+        const { assert!(PSIN == D + LIT_PARAMETERS) };
+
+        let color = self.material.run_with_derivatives(
+            shader_params.fixed_rows::<D>(0).into_owned(),
+            &derivatives.rows(0),
+        );
+        self.light(color, shader_params)
+    }
+}
+
+// Writes the id from the TriangleId geometry shader as a color, so the
+// triangle under a pixel can be found by reading the color buffer back.
+// Ids are offset by one to leave black for the background.
+pub struct IdColor;
+
+impl IdColor {
+    pub fn encode(id: usize) -> Color {
+        let id = id + 1;
+        Color::new(id as u8, (id >> 8) as u8, (id >> 16) as u8)
+    }
+
+    // Returns None for pixels not covered by any triangle
+    pub fn decode(color: Color) -> Option<usize> {
+        let id = color.r as usize | (color.g as usize) << 8 | (color.b as usize) << 16;
+        id.checked_sub(1)
+    }
+}
+
+impl PixelShader<1> for IdColor {
+    fn run(&self, shader_params: SVector<f32, 1>) -> Color {
+        // The id is the same at every vertex, but
+        // interpolating it can still add a little error
+        Self::encode(shader_params.x.round().max(0.0) as usize)
+    }
+}
+
+// Draws the edges of each triangle over another material, which reads
+// the first D parameters. The last three are the barycentric coordinates
+// from the Barycentric geometry shader.
+pub struct Wireframe<M, const D: usize> {
+    pub material: M,
+    pub color: Color,
+    // How far from an edge pixels are still part of the line,
+    // as a fraction of the distance to the opposite corner
+    pub width: f32,
+}
+
+impl<M, const D: usize> Wireframe<M, D> {
+    pub fn new(material: M) -> Self {
+        Self {
+            material,
+            color: Color::new(255, 255, 255),
+            width: 0.03,
+        }
+    }
+}
+
+impl<M: PixelShader<D>, const D: usize, const PSIN: usize> PixelShader<PSIN> for Wireframe<M, D> {
+    fn run(&self, shader_params: SVector<f32, PSIN>) -> Color {
+        const { assert!(PSIN == D + 3) };
+
+        if shader_params.fixed_rows::<3>(D).min() < self.width {
+            self.color
+        } else {
+            self.material
+                .run(shader_params.fixed_rows::<D>(0).into_owned())
+        }
+    }
+
+    fn uses_derivatives(&self) -> bool {
+        self.material.uses_derivatives()
+    }
+
+    fn run_with_derivatives(
+        &self,
+        shader_params: SVector<f32, PSIN>,
+        derivatives: &Derivatives<PSIN>,
+    ) -> Color {
+        const { assert!(PSIN == D + 3) };
+
+        if shader_params.fixed_rows::<3>(D).min() < self.width {
+            self.color
+        } else {
+            self.material.run_with_derivatives(
+                shader_params.fixed_rows::<D>(0).into_owned(),
+                &derivatives.rows(0),
+            )
+        }
+    }
+}
+
+// Fades the alpha of another material, which reads the first D parameters,
+// out to the edges of a point sprite. The last two parameters are the uvs
+// on the sprite, so PSIN must be D + 2. Draw with a blend mode to see the
+// points as soft circles rather than squares.
+pub struct RoundSprite<M, const D: usize> {
+    pub material: M,
+}
+
+impl<M, const D: usize> RoundSprite<M, D> {
+    fn fade<const PSIN: usize>(&self, color: Color, shader_params: SVector<f32, PSIN>) -> Color {
+        // Distance from the center of the sprite, 1 at the edges
+        let offset = shader_params.fixed_rows::<2>(D) * 2.0 - SVector::<f32, 2>::repeat(1.0);
+        let fade = (1.0 - offset.norm_squared()).max(0.0);
+
+        Color::rgba(color.r, color.g, color.b, (color.a as f32 * fade) as u8)
+    }
+}
+
+impl<M: PixelShader<D>, const D: usize, const PSIN: usize> PixelShader<PSIN> for RoundSprite<M, D> {
+    fn run(&self, shader_params: SVector<f32, PSIN>) -> Color {
+        const { assert!(PSIN == D + 2) };
+
+        let color = self
+            .material
+            .run(shader_params.fixed_rows::<D>(0).into_owned());
+        self.fade(color, shader_params)
+    }
+
+    fn uses_derivatives(&self) -> bool {
+        self.material.uses_derivatives()
+    }
+
+    fn run_with_derivatives(
+        &self,
+        shader_params: SVector<f32, PSIN>,
+        derivatives: &Derivatives<PSIN>,
+    ) -> Color {
+        const { assert!(PSIN == D + 2) };
+
+        let color = self.material.run_with_derivatives(
+            shader_params.fixed_rows::<D>(0).into_owned(),
+            &derivatives.rows(0),
+        );
+        self.fade(color, shader_params)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn materials_pass_on_whether_they_use_derivatives() {
+        const LIT: usize = 2 + LIT_PARAMETERS;
+
+        let linear = Sampler {
+            mipmap_mode: MipmapMode::Linear,
+            ..Sampler::default()
+        };
+        let textured =
+            || Textured::new(Rc::new(image::texture().with_mipmaps())).with_sampler(linear);
+        let color = ColorBlend::default;
+
+        // Without mip levels there is nothing to pick between
+        assert!(!PixelShader::<2>::uses_derivatives(&Textured::default()));
+        assert!(!PixelShader::<2>::uses_derivatives(
+            &Textured::default().with_sampler(linear)
+        ));
+        assert!(PixelShader::<2>::uses_derivatives(&textured()));
+        assert!(!PixelShader::<3>::uses_derivatives(&color()));
+        assert!(!IdColor.uses_derivatives());
+
+        let lit = Lit::<_, 2>::new(textured(), Lighting::default());
+        assert!(PixelShader::<LIT>::uses_derivatives(&lit));
+        assert!(PixelShader::<5>::uses_derivatives(&Wireframe::<_, 2>::new(
+            textured()
+        )));
+        assert!(PixelShader::<4>::uses_derivatives(&RoundSprite::<_, 2> {
+            material: textured()
+        }));
+
+        let wireframe = Wireframe::<_, 3>::new(color());
+        assert!(!PixelShader::<6>::uses_derivatives(&wireframe));
+        let sprite = RoundSprite::<_, 3> { material: color() };
+        assert!(!PixelShader::<5>::uses_derivatives(&sprite));
     }
 }
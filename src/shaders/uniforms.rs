@@ -0,0 +1,45 @@
+use nalgebra::{Perspective3, Transform3};
+
+use crate::transform::ModelViewProjection;
+
+// Shader inputs which stay the same for every draw in a frame
+#[derive(Clone, Copy)]
+pub struct FrameUniforms {
+    pub view: Transform3<f32>,
+    pub projection: Perspective3<f32>,
+}
+
+impl FrameUniforms {
+    // Takes the size of the render target, which sets the aspect ratio
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            view: Transform3::identity(),
+            projection: projection(width, height),
+        }
+    }
+
+    // Combines the frame's camera with the transform of a single draw
+    pub fn with_model(&self, model: Transform3<f32>) -> TransformUniforms {
+        TransformUniforms {
+            mvp: ModelViewProjection::new(&model, &self.view, &self.projection),
+        }
+    }
+}
+
+fn projection(width: usize, height: usize) -> Perspective3<f32> {
+    // 103 for 16:9
+    let aspect_ratio = width as f32 / height as f32;
+    let hfov = 103f32.to_radians();
+    let vfov = 2.0 * ((hfov / 2.0).tan() * aspect_ratio.recip()).atan();
+    let close = 1.0;
+    let far = 1000.0;
+
+    Perspective3::new(aspect_ratio, vfov, close, far)
+}
+
+// Uniforms for vertex shaders which transform
+// each object from model space to clip space
+#[derive(Clone, Copy)]
+pub struct TransformUniforms {
+    pub mvp: ModelViewProjection,
+}
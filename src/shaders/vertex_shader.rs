@@ -1,71 +1,64 @@
-use std::mem::MaybeUninit;
-
-use gamercade_rs::prelude::console_log;
-use nalgebra::{Perspective3, Transform3, Vector4};
+use nalgebra::SVector;
 
 use crate::types::{RawPoint, TriangleVertex};
-static mut MODEL: MaybeUninit<Transform3<f32>> = MaybeUninit::uninit();
-static mut VIEW: MaybeUninit<Transform3<f32>> = MaybeUninit::uninit();
-static mut PROJECTION: MaybeUninit<Perspective3<f32>> = MaybeUninit::uninit();
 
-// Processes verticies and places them into the output buffer
+use super::{TransformUniforms, LIT_PARAMETERS};
+
+// Processes verticies and places them into the output buffer.
+// Uniforms are passed in with each draw.
 pub trait VertexShader<const VSIN: usize, const VSOUT: usize> {
-    fn run(vertex: &RawPoint<VSIN>) -> TriangleVertex<VSOUT>;
-}
+    type Uniforms;
 
-pub fn get_projection_matrix() -> Perspective3<f32> {
-    unsafe { PROJECTION.assume_init() }
+    fn run(&self, vertex: &RawPoint<VSIN>, uniforms: &Self::Uniforms) -> TriangleVertex<VSOUT>;
 }
 
-pub fn bind_view_matrix(view: Transform3<f32>) {
-    unsafe {
-        VIEW.write(view);
-    }
-}
+pub struct DefaultVertexShader;
 
-pub fn get_model_matrix() -> Transform3<f32> {
-    unsafe { MODEL.assume_init() }
-}
+impl<const VSINOUT: usize> VertexShader<VSINOUT, VSINOUT> for DefaultVertexShader {
+    type Uniforms = TransformUniforms;
 
-pub fn bind_model_matrix(model: Transform3<f32>) {
-    unsafe {
-        MODEL.write(model);
+    fn run(
+        &self,
+        vertex: &RawPoint<VSINOUT>,
+        uniforms: &TransformUniforms,
+    ) -> TriangleVertex<VSINOUT> {
+        TriangleVertex {
+            position: uniforms.mvp.transform_point(&vertex.position),
+            parameters: vertex.parameters,
+        }
     }
 }
 
-pub fn init_projection(screen_width: usize, screen_height: usize) {
-    // 103 for 16:9
-    let aspect_ratio = screen_width as f32 / screen_height as f32;
-    let hfov = 103f32.to_radians();
-    let vfov = 2.0 * ((hfov / 2.0).tan() * aspect_ratio.recip()).atan();
-    console_log(&format!("vertical fov: {}", vfov.to_degrees()));
-    let close = 1.0;
-    let far = 1000.0;
+// Passes the parameters through like the default shader, followed by what
+// Lit pixel shaders need to light the surface, so VSOUT must be VSIN + 9.
+pub struct LitVertexShader;
 
-    unsafe {
-        PROJECTION.write(Perspective3::new(aspect_ratio, vfov, close, far));
-    }
-}
+impl<const VSIN: usize, const VSOUT: usize> VertexShader<VSIN, VSOUT> for LitVertexShader {
+    type Uniforms = TransformUniforms;
 
-pub fn get_view_matrix() -> Transform3<f32> {
-    unsafe { VIEW.assume_init() }
-}
+    fn run(&self, vertex: &RawPoint<VSIN>, uniforms: &TransformUniforms) -> TriangleVertex<VSOUT> {
+        const { assert!(VSOUT == VSIN + LIT_PARAMETERS) };
 
-pub struct DefaultVertexShader;
-
-impl<const VSINOUT: usize> VertexShader<VSINOUT, VSINOUT> for DefaultVertexShader {
-    fn run(vertex: &RawPoint<VSINOUT>) -> TriangleVertex<VSINOUT> {
-        let position: Vector4<f32> = vertex.position.into();
-        let model = get_model_matrix();
-        let view = get_view_matrix();
-        let projection = get_projection_matrix();
+        let mvp = &uniforms.mvp;
+        let world_position = mvp.model().transform_point(&vertex.position);
+        let normal = mvp.transform_normal(&vertex.normal);
+        let to_camera = mvp.camera_position() - world_position;
 
-        let mvp = projection.as_matrix() * (model * view).to_homogeneous();
-        let position = mvp * position;
+        let mut parameters = SVector::<f32, VSOUT>::zeros();
+        parameters
+            .fixed_rows_mut::<VSIN>(0)
+            .copy_from(&vertex.parameters);
+        parameters
+            .fixed_rows_mut::<3>(VSIN)
+            .copy_from(&world_position.coords);
+        parameters.fixed_rows_mut::<3>(VSIN + 3).copy_from(&normal);
+        parameters
+            .fixed_rows_mut::<3>(VSIN + 6)
+            .copy_from(&to_camera);
 
         TriangleVertex {
-            position,
-            parameters: vertex.parameters,
+            position: mvp.transform_point(&vertex.position),
+            parameters,
         }
     }
 }
@@ -17,6 +17,8 @@ pub const CUBE_EDGES: &[TriangleEdge; 12] = &[
     TriangleEdge(6, 4),
 ];
 
+// The demo draws the faceted cube, these are only drawn by the golden tests
+#[cfg(test)]
 pub const CUBE_INCIDES: [IndexedTriangle; 12] = [
     IndexedTriangle(0, 1, 2),
     IndexedTriangle(2, 1, 3),
@@ -43,6 +45,7 @@ pub const CUBE_COLORS: &[Vector3<f32>; 8] = &[
     Vector3::new(1.0, 0.0, 1.0),
 ];
 
+#[cfg(test)]
 pub const CUBE_UVS: &[Vector2<f32>; 8] = &[
     Vector2::new(0.0, 0.0),
     Vector2::new(1.0, 0.0),
@@ -69,6 +72,61 @@ pub fn cube(size: f32) -> [Point3<f32>; 8] {
     ]
 }
 
+// Normals pointing out from the center of the cube through each
+// vertex, which gives it a rounded look when lit
+pub fn cube_normals() -> [Vector3<f32>; 8] {
+    cube(SIDE).map(|position| position.coords.normalize())
+}
+
+// The normal of each face, and the direction which is up in its texture
+const CUBE_FACES: [(Vector3<f32>, Vector3<f32>); 6] = [
+    (Vector3::new(0.0, 0.0, 1.0), Vector3::new(0.0, 1.0, 0.0)),
+    (Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0)),
+    (Vector3::new(0.0, 0.0, -1.0), Vector3::new(0.0, 1.0, 0.0)),
+    (Vector3::new(-1.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0)),
+    (Vector3::new(0.0, 1.0, 0.0), Vector3::new(0.0, 0.0, -1.0)),
+    (Vector3::new(0.0, -1.0, 0.0), Vector3::new(0.0, 0.0, 1.0)),
+];
+
+// Corners of a face, as steps along its right and up directions
+const FACE_CORNERS: [(f32, f32); 4] = [(1.0, -1.0), (-1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)];
+
+// A cube where each face has its own four vertices, so they
+// can have flat normals and uvs instead of sharing them
+pub fn faceted_cube(size: f32) -> [Point3<f32>; 24] {
+    let side = size * 0.5;
+    std::array::from_fn(|index| {
+        let (normal, up) = CUBE_FACES[index / 4];
+        let right = up.cross(&normal);
+        let (x, y) = FACE_CORNERS[index % 4];
+        Point3::from((normal + right * x + up * y) * side)
+    })
+}
+
+pub fn faceted_cube_normals() -> [Vector3<f32>; 24] {
+    std::array::from_fn(|index| CUBE_FACES[index / 4].0)
+}
+
+// Every face shows the whole texture, upright
+pub fn faceted_cube_uvs() -> [Vector2<f32>; 24] {
+    std::array::from_fn(|index| {
+        let (x, y) = FACE_CORNERS[index % 4];
+        Vector2::new((x + 1.0) * 0.5, (1.0 - y) * 0.5)
+    })
+}
+
+pub const FACETED_CUBE_INDICES: [IndexedTriangle; 12] = {
+    let mut indices = [IndexedTriangle(0, 0, 0); 12];
+    let mut face = 0;
+    while face < 6 {
+        let first = face * 4;
+        indices[face * 2] = IndexedTriangle(first, first + 1, first + 2);
+        indices[face * 2 + 1] = IndexedTriangle(first + 2, first + 1, first + 3);
+        face += 1;
+    }
+    indices
+};
+
 pub fn plane(size: f32) -> [Point3<f32>; 4] {
     let side = size;
     [
@@ -86,5 +144,25 @@ pub const PLANE_UVS: &[Vector2<f32>; 4] = &[
     Vector2::new(0.0, 1.0),
 ];
 
+// The demo draws the plane as a fan instead
+#[cfg(test)]
 pub const PLANE_INDICES: &[IndexedTriangle; 2] =
     &[IndexedTriangle(0, 1, 2), IndexedTriangle(0, 2, 3)];
+
+// The plane faces +z
+pub const PLANE_NORMAL: Vector3<f32> = Vector3::new(0.0, 0.0, 1.0);
+
+// Spreads points evenly over a sphere, along a spiral from top to bottom
+pub fn sphere_points(count: usize, radius: f32) -> Vec<Point3<f32>> {
+    let golden_angle = std::f32::consts::PI * (3.0 - 5f32.sqrt());
+
+    (0..count)
+        .map(|index| {
+            let y = 1.0 - (index as f32 + 0.5) / count as f32 * 2.0;
+            let ring = (1.0 - y * y).sqrt();
+            let angle = golden_angle * index as f32;
+
+            Point3::new(angle.cos() * ring, y, angle.sin() * ring) * radius
+        })
+        .collect()
+}
@@ -0,0 +1,309 @@
+use std::rc::Rc;
+
+use nalgebra::Vector2;
+
+use crate::types::Color;
+
+// An image for pixel shaders to sample, stored row by row from the top left
+#[derive(Clone, Debug)]
+pub struct Texture {
+    width: usize,
+    height: usize,
+    texels: Box<[Color]>,
+    // Smaller copies for sampling from far away, see with_mipmaps
+    mips: Box<[Texture]>,
+}
+
+impl Texture {
+    pub fn new(width: usize, height: usize, texels: impl Into<Box<[Color]>>) -> Self {
+        let texels = texels.into();
+        assert!(width > 0 && height > 0, "textures can't be empty");
+        assert_eq!(
+            texels.len(),
+            width * height,
+            "a {width}x{height} texture needs {} texels",
+            width * height
+        );
+
+        Self {
+            width,
+            height,
+            texels,
+            mips: Box::new([]),
+        }
+    }
+
+    // A single color, for materials which don't need an image
+    pub fn solid(color: Color) -> Self {
+        Self::new(1, 1, [color])
+    }
+
+    // Alternating squares of size texels, starting with a in the top left
+    pub fn checkerboard(width: usize, height: usize, size: usize, a: Color, b: Color) -> Self {
+        assert!(size > 0, "checkerboard squares must be at least one texel");
+
+        let texels = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| match (x / size + y / size) % 2 {
+                0 => a,
+                _ => b,
+            })
+            .collect::<Vec<_>>();
+
+        Self::new(width, height, texels)
+    }
+
+    // Reads a texture packed by build.rs, the width and height
+    // as little endian u32s followed by the rgba texels
+    pub fn from_packed(bytes: &[u8]) -> Self {
+        let size = |offset: usize| {
+            u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap()) as usize
+        };
+
+        let texels = bytes[8..]
+            .chunks_exact(4)
+            .map(|rgba| Color::rgba(rgba[0], rgba[1], rgba[2], rgba[3]))
+            .collect::<Vec<_>>();
+
+        Self::new(size(0), size(4), texels)
+    }
+
+    // Decodes one sprite in the format Gamercade stores sprite sheets in,
+    // a palette index per pixel with every sprite one after the other.
+    // Palette colors are 0xRRGGBBAA, like in the editor's files.
+    pub fn from_sprite(
+        width: usize,
+        height: usize,
+        sprites: &[u8],
+        sprite_index: usize,
+        palette: &[u32],
+    ) -> Self {
+        let size = width * height;
+        let texels = sprites[sprite_index * size..(sprite_index + 1) * size]
+            .iter()
+            .map(|&index| {
+                let [r, g, b, a] = palette[index as usize].to_be_bytes();
+                Color::rgba(r, g, b, a)
+            })
+            .collect::<Vec<_>>();
+
+        Self::new(width, height, texels)
+    }
+
+    // Adds a chain of mip levels, each half the size of the one before down
+    // to 1x1, with every texel the average of four in the level above.
+    // Odd sizes are rounded up, so the last row or column is still kept.
+    pub fn with_mipmaps(mut self) -> Self {
+        let mut mips = Vec::new();
+        while let Some(mip) = mips.last().unwrap_or(&self).half_size() {
+            mips.push(mip);
+        }
+
+        self.mips = mips.into_boxed_slice();
+        self
+    }
+
+    fn half_size(&self) -> Option<Self> {
+        if self.width == 1 && self.height == 1 {
+            return None;
+        }
+
+        let (width, height) = (self.width.div_ceil(2), self.height.div_ceil(2));
+        let texels = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| {
+                let (left, top) = (x * 2, y * 2);
+                let (right, bottom) = (
+                    (left + 1).min(self.width - 1),
+                    (top + 1).min(self.height - 1),
+                );
+                let block = [
+                    self.texel(left, top),
+                    self.texel(right, top),
+                    self.texel(left, bottom),
+                    self.texel(right, bottom),
+                ];
+
+                let average = |channel: fn(&Color) -> u8| {
+                    let sum = block.iter().map(|texel| channel(texel) as u32).sum::<u32>();
+                    ((sum + 2) / 4) as u8
+                };
+                Color::rgba(
+                    average(|texel| texel.r),
+                    average(|texel| texel.g),
+                    average(|texel| texel.b),
+                    average(|texel| texel.a),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        Some(Self::new(width, height, texels))
+    }
+
+    // The number of mip levels, including the full size texture
+    pub fn mip_levels(&self) -> usize {
+        self.mips.len() + 1
+    }
+
+    // Level 0 is the texture itself, and levels past the smallest mip clamp to it
+    pub fn level(&self, level: usize) -> &Texture {
+        match level.min(self.mips.len()) {
+            0 => self,
+            level => &self.mips[level - 1],
+        }
+    }
+
+    // The mip level which has about one texel per pixel, for uvs changing
+    // by dx and dy to the next pixel. Negative when the texture is magnified.
+    pub fn lod(&self, dx: Vector2<f32>, dy: Vector2<f32>) -> f32 {
+        let size = Vector2::new(self.width as f32, self.height as f32);
+        let texels_per_pixel = dx
+            .component_mul(&size)
+            .norm()
+            .max(dy.component_mul(&size).norm());
+
+        texels_per_pixel.log2()
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn texels(&self) -> &[Color] {
+        &self.texels
+    }
+
+    pub fn texel(&self, x: usize, y: usize) -> Color {
+        self.texels[y * self.width + x]
+    }
+}
+
+// Identifies a texture added to a Textures registry
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct TextureHandle(usize);
+
+// Every texture loaded by the game. Materials hold on to the ones they
+// sample, so textures stay alive as long as something is using them.
+#[derive(Default)]
+pub struct Textures {
+    textures: Vec<Rc<Texture>>,
+}
+
+impl Textures {
+    pub fn add(&mut self, texture: Texture) -> TextureHandle {
+        self.textures.push(Rc::new(texture));
+        TextureHandle(self.textures.len() - 1)
+    }
+
+    pub fn get(&self, handle: TextureHandle) -> &Rc<Texture> {
+        &self.textures[handle.0]
+    }
+
+    pub fn len(&self) -> usize {
+        self.textures.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.textures.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WHITE: Color = Color::new(255, 255, 255);
+    const BLACK: Color = Color::new(0, 0, 0);
+
+    #[test]
+    fn textures_can_have_any_size() {
+        let texture = Texture::checkerboard(5, 3, 2, WHITE, BLACK);
+
+        assert_eq!((texture.width(), texture.height()), (5, 3));
+        assert_eq!(texture.texel(0, 0), WHITE);
+        assert_eq!(texture.texel(2, 0), BLACK);
+        assert_eq!(texture.texel(3, 2), WHITE);
+    }
+
+    #[test]
+    fn packed_textures_start_with_their_size() {
+        let packed = [1, 0, 0, 0, 2, 0, 0, 0, 1, 2, 3, 4, 5, 6, 7, 8];
+        let texture = Texture::from_packed(&packed);
+
+        assert_eq!((texture.width(), texture.height()), (1, 2));
+        assert_eq!(texture.texel(0, 1), Color::rgba(5, 6, 7, 8));
+    }
+
+    #[test]
+    fn sprites_are_looked_up_in_the_palette() {
+        let palette = [0x000000ff, 0xff000080, 0x00ff00ff];
+        let sprites = [0, 0, 0, 0, 1, 2, 2, 1];
+        let texture = Texture::from_sprite(2, 2, &sprites, 1, &palette);
+
+        assert_eq!(texture.texel(0, 0), Color::rgba(255, 0, 0, 128));
+        assert_eq!(texture.texel(1, 0), Color::new(0, 255, 0));
+        assert_eq!(texture.texel(0, 1), Color::new(0, 255, 0));
+    }
+
+    #[test]
+    fn mipmaps_average_down_to_one_texel() {
+        let texture = Texture::checkerboard(8, 4, 1, WHITE, BLACK).with_mipmaps();
+
+        assert_eq!(texture.mip_levels(), 4);
+        assert_eq!(
+            (texture.level(1).width(), texture.level(1).height()),
+            (4, 2)
+        );
+        assert_eq!(
+            (texture.level(3).width(), texture.level(3).height()),
+            (1, 1)
+        );
+        assert_eq!(texture.level(3).texel(0, 0), Color::new(128, 128, 128));
+        assert_eq!(texture.level(9).width(), 1);
+
+        // One texel per pixel is level 0, and each halving goes down one
+        let size = |texels: f32| Vector2::new(texels / 8.0, 0.0);
+        assert_eq!(texture.lod(size(1.0), Vector2::zeros()), 0.0);
+        assert_eq!(texture.lod(size(0.5), size(4.0)), 2.0);
+    }
+
+    #[test]
+    fn odd_sized_mipmaps_keep_the_edges() {
+        // Only the bottom right texel is white, which the 2x2 level
+        // averages with itself rather than leaving out
+        let mut texels = [BLACK; 9];
+        texels[8] = WHITE;
+        let texture = Texture::new(3, 3, texels).with_mipmaps();
+
+        let level = texture.level(1);
+        assert_eq!((level.width(), level.height()), (2, 2));
+        assert_eq!(level.texels(), [BLACK, BLACK, BLACK, WHITE]);
+        assert_eq!(texture.level(2).texel(0, 0), Color::new(64, 64, 64));
+    }
+
+    #[test]
+    #[should_panic]
+    fn texels_must_fill_the_texture() {
+        Texture::new(4, 4, [WHITE; 15]);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one texel")]
+    fn checkerboard_squares_must_not_be_empty() {
+        Texture::checkerboard(4, 4, 0, WHITE, BLACK);
+    }
+
+    #[test]
+    fn handles_find_their_texture() {
+        let mut textures = Textures::default();
+        let white = textures.add(Texture::solid(WHITE));
+        let black = textures.add(Texture::solid(BLACK));
+
+        assert_eq!(textures.get(white).texel(0, 0), WHITE);
+        assert_eq!(textures.get(black).texel(0, 0), BLACK);
+    }
+}
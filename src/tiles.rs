@@ -0,0 +1,138 @@
+use crate::{
+    gpu::{RenderTarget, ZBuffer},
+    graphics::Rasterizer,
+    shaders::PixelShader,
+    types::{Rect, Triangle},
+};
+
+pub const DEFAULT_TILE_SIZE: usize = 16;
+
+// Sorts screen space triangles into bins of fixed size screen tiles, and then
+// rasterizes one tile at a time into a small tile local depth buffer.
+// This keeps the depth values being worked on close together in memory.
+pub struct Tiler {
+    tile_size: usize,
+    tiles_x: usize,
+    tiles_y: usize,
+    screen: Rect,
+    // The indices of the triangles touching each tile, in draw order
+    bins: Box<[Vec<usize>]>,
+    tile_z_buffer: ZBuffer,
+}
+
+impl Tiler {
+    // The tiles are laid out over the render target on the first draw
+    pub fn new(tile_size: usize) -> Self {
+        let tile_size = tile_size.max(1);
+
+        Self {
+            tile_size,
+            tiles_x: 0,
+            tiles_y: 0,
+            screen: Rect {
+                x_start: 0,
+                y_start: 0,
+                x_end: 0,
+                y_end: 0,
+            },
+            bins: Box::new([]),
+            tile_z_buffer: ZBuffer::new(tile_size, tile_size),
+        }
+    }
+
+    // Lays the tiles out over a render target of the given size
+    fn resize(&mut self, screen_width: usize, screen_height: usize) {
+        if self.screen.x_end == screen_width as i32 && self.screen.y_end == screen_height as i32 {
+            return;
+        }
+
+        self.tiles_x = screen_width.div_ceil(self.tile_size);
+        self.tiles_y = screen_height.div_ceil(self.tile_size);
+        self.screen.x_end = screen_width as i32;
+        self.screen.y_end = screen_height as i32;
+        self.bins = (0..self.tiles_x * self.tiles_y)
+            .map(|_| Vec::new())
+            .collect::<Vec<_>>()
+            .into_boxed_slice();
+    }
+
+    pub fn tile_size(&self) -> usize {
+        self.tile_size
+    }
+
+    // Bins and rasterizes the screen space triangles. Each tile touched
+    // is loaded from, and stored back to, the full screen depth buffer
+    // so depth testing still works between separate draws.
+    // Colors are written straight to the target's color buffer.
+    pub fn draw<PS: PixelShader<D> + ?Sized, const D: usize>(
+        &mut self,
+        triangles: &[Triangle<D>],
+        shader: &PS,
+        target: &mut RenderTarget,
+        rasterizer: Rasterizer,
+    ) {
+        self.resize(target.z_buffer.width, target.z_buffer.height);
+        self.bin(triangles);
+
+        for (index, bin) in self.bins.iter_mut().enumerate() {
+            if bin.is_empty() {
+                continue;
+            }
+
+            let x_start = ((index % self.tiles_x) * self.tile_size) as i32;
+            let y_start = ((index / self.tiles_x) * self.tile_size) as i32;
+            let region = Rect {
+                x_start,
+                y_start,
+                x_end: x_start + self.tile_size as i32,
+                y_end: y_start + self.tile_size as i32,
+            }
+            .intersect(self.screen);
+
+            self.tile_z_buffer.load_tile(target.z_buffer, region);
+
+            let mut tile_target = RenderTarget {
+                color_buffer: target.color_buffer,
+                z_buffer: &mut self.tile_z_buffer,
+                blend_mode: target.blend_mode,
+                depth_state: target.depth_state,
+            };
+            bin.drain(..).for_each(|triangle| {
+                rasterizer.draw(&triangles[triangle], shader, &mut tile_target)
+            });
+
+            self.tile_z_buffer.store_tile(target.z_buffer);
+        }
+    }
+
+    // Adds each triangle to the bins of every tile its bounding box touches
+    fn bin<const D: usize>(&mut self, triangles: &[Triangle<D>]) {
+        let tile_size = self.tile_size as f32;
+        let max_x = self.tiles_x as i32 - 1;
+        let max_y = self.tiles_y as i32 - 1;
+
+        for (index, triangle) in triangles.iter().enumerate() {
+            let (min, max) = triangle.vertices.iter().fold(
+                (
+                    (f32::INFINITY, f32::INFINITY),
+                    (f32::NEG_INFINITY, f32::NEG_INFINITY),
+                ),
+                |(min, max), vertex| {
+                    let (x, y) = (vertex.position.x, vertex.position.y);
+                    ((min.0.min(x), min.1.min(y)), (max.0.max(x), max.1.max(y)))
+                },
+            );
+
+            let tile_x_start = ((min.0 / tile_size).floor() as i32).max(0);
+            let tile_x_end = ((max.0 / tile_size).floor() as i32).min(max_x);
+            let tile_y_start = ((min.1 / tile_size).floor() as i32).max(0);
+            let tile_y_end = ((max.1 / tile_size).floor() as i32).min(max_y);
+
+            for tile_y in tile_y_start..=tile_y_end {
+                for tile_x in tile_x_start..=tile_x_end {
+                    self.bins[tile_x as usize + tile_y as usize * self.tiles_x].push(index);
+                }
+            }
+        }
+    }
+}
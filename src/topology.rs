@@ -0,0 +1,197 @@
+use crate::types::IndexedTriangle;
+
+// How a sequence of vertex indices is split into triangles. The
+// variants are named like the topologies of other graphics apis.
+#[allow(clippy::enum_variant_names)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum Topology {
+    // Every three indices are a separate triangle
+    #[default]
+    TriangleList,
+    // Each index after the first two makes a triangle with the previous
+    // two. Every other triangle is flipped to keep the same winding.
+    TriangleStrip,
+    // Each index after the first two makes a triangle
+    // with the previous one and the very first
+    TriangleFan,
+}
+
+// Vertex indices for a draw, borrowed from wherever they are stored
+#[derive(Clone, Copy)]
+pub enum Indices<'a> {
+    // No index buffer, the vertices are used in order
+    None,
+    U16(&'a [u16]),
+    U32(&'a [u32]),
+    // Flattened to a sequence of three indices per triangle
+    Triangles(&'a [IndexedTriangle]),
+}
+
+impl Indices<'_> {
+    // The number of indices, which are all the vertices when there are none
+    fn len(&self, vertex_count: usize) -> usize {
+        match self {
+            Self::None => vertex_count,
+            Self::U16(indices) => indices.len(),
+            Self::U32(indices) => indices.len(),
+            Self::Triangles(triangles) => triangles.len() * 3,
+        }
+    }
+
+    fn get(&self, index: usize) -> usize {
+        match self {
+            Self::None => index,
+            Self::U16(indices) => indices[index] as usize,
+            Self::U32(indices) => indices[index] as usize,
+            Self::Triangles(triangles) => {
+                let triangle = &triangles[index / 3];
+                match index % 3 {
+                    0 => triangle.0,
+                    1 => triangle.1,
+                    _ => triangle.2,
+                }
+            }
+        }
+    }
+}
+
+// The vertices to draw for a mesh, and how they make up triangles
+#[derive(Clone, Copy)]
+pub struct Primitives<'a> {
+    pub topology: Topology,
+    pub indices: Indices<'a>,
+}
+
+impl<'a> Primitives<'a> {
+    pub fn new(topology: Topology, indices: Indices<'a>) -> Self {
+        Self { topology, indices }
+    }
+
+    // Calls f with the vertex indices of each triangle. Any
+    // indices left over at the end of a list are ignored.
+    pub fn for_each_triangle(&self, vertex_count: usize, mut f: impl FnMut(IndexedTriangle)) {
+        let indices = &self.indices;
+        let len = indices.len(vertex_count);
+
+        match self.topology {
+            Topology::TriangleList => (0..len / 3).for_each(|triangle| {
+                let first = triangle * 3;
+                f(IndexedTriangle(
+                    indices.get(first),
+                    indices.get(first + 1),
+                    indices.get(first + 2),
+                ))
+            }),
+            Topology::TriangleStrip => (0..len.saturating_sub(2)).for_each(|first| {
+                let (a, b, c) = (
+                    indices.get(first),
+                    indices.get(first + 1),
+                    indices.get(first + 2),
+                );
+                match first % 2 {
+                    0 => f(IndexedTriangle(a, b, c)),
+                    _ => f(IndexedTriangle(b, a, c)),
+                }
+            }),
+            Topology::TriangleFan => (0..len.saturating_sub(2)).for_each(|first| {
+                f(IndexedTriangle(
+                    indices.get(0),
+                    indices.get(first + 1),
+                    indices.get(first + 2),
+                ))
+            }),
+        }
+    }
+}
+
+impl<'a> From<&'a [IndexedTriangle]> for Primitives<'a> {
+    fn from(triangles: &'a [IndexedTriangle]) -> Self {
+        Self::new(Topology::TriangleList, Indices::Triangles(triangles))
+    }
+}
+
+impl<'a, const N: usize> From<&'a [IndexedTriangle; N]> for Primitives<'a> {
+    fn from(triangles: &'a [IndexedTriangle; N]) -> Self {
+        Self::new(Topology::TriangleList, Indices::Triangles(triangles))
+    }
+}
+
+// Owned indices, for meshes which keep their own
+pub enum IndexBuffer {
+    None,
+    U16(Box<[u16]>),
+    U32(Box<[u32]>),
+    Triangles(Box<[IndexedTriangle]>),
+}
+
+impl IndexBuffer {
+    pub fn as_indices(&self) -> Indices<'_> {
+        match self {
+            Self::None => Indices::None,
+            Self::U16(indices) => Indices::U16(indices),
+            Self::U32(indices) => Indices::U32(indices),
+            Self::Triangles(triangles) => Indices::Triangles(triangles),
+        }
+    }
+}
+
+impl From<Box<[IndexedTriangle]>> for IndexBuffer {
+    fn from(triangles: Box<[IndexedTriangle]>) -> Self {
+        Self::Triangles(triangles)
+    }
+}
+
+impl<const N: usize> From<[IndexedTriangle; N]> for IndexBuffer {
+    fn from(triangles: [IndexedTriangle; N]) -> Self {
+        Self::Triangles(Box::new(triangles))
+    }
+}
+
+impl From<Box<[u16]>> for IndexBuffer {
+    fn from(indices: Box<[u16]>) -> Self {
+        Self::U16(indices)
+    }
+}
+
+impl From<Box<[u32]>> for IndexBuffer {
+    fn from(indices: Box<[u32]>) -> Self {
+        Self::U32(indices)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn triangles(primitives: Primitives, vertex_count: usize) -> Vec<(usize, usize, usize)> {
+        let mut triangles = Vec::new();
+        primitives.for_each_triangle(vertex_count, |triangle| {
+            triangles.push((triangle.0, triangle.1, triangle.2))
+        });
+        triangles
+    }
+
+    #[test]
+    fn lists_ignore_leftover_indices() {
+        let indices = [4u16, 5, 6, 7, 8, 9, 10];
+        let primitives = Primitives::new(Topology::TriangleList, Indices::U16(&indices));
+
+        assert_eq!(triangles(primitives, 11), [(4, 5, 6), (7, 8, 9)]);
+    }
+
+    #[test]
+    fn strips_keep_their_winding() {
+        let indices = [0u32, 1, 2, 3, 4];
+        let primitives = Primitives::new(Topology::TriangleStrip, Indices::U32(&indices));
+
+        assert_eq!(triangles(primitives, 5), [(0, 1, 2), (2, 1, 3), (2, 3, 4)]);
+    }
+
+    #[test]
+    fn fans_share_the_first_vertex() {
+        let primitives = Primitives::new(Topology::TriangleFan, Indices::None);
+
+        assert_eq!(triangles(primitives, 5), [(0, 1, 2), (0, 2, 3), (0, 3, 4)]);
+        assert_eq!(triangles(primitives, 2), []);
+    }
+}
@@ -0,0 +1,156 @@
+use nalgebra::{Matrix3, Matrix4, Perspective3, Point3, Transform3, Vector3, Vector4};
+
+// The transforms of a single draw, from model space to clip space.
+// The combined matrix is built once when the draw is set up,
+// rather than for every vertex it transforms.
+#[derive(Clone, Copy)]
+pub struct ModelViewProjection {
+    model: Transform3<f32>,
+    matrix: Matrix4<f32>,
+    normal_matrix: Matrix3<f32>,
+    camera_position: Point3<f32>,
+}
+
+impl ModelViewProjection {
+    pub fn new(
+        model: &Transform3<f32>,
+        view: &Transform3<f32>,
+        projection: &Perspective3<f32>,
+    ) -> Self {
+        // The rightmost transform is applied first, so vertices move from
+        // model space into the world, then into the camera's view space,
+        // and are only then projected
+        let matrix = projection.as_matrix() * (view * model).to_homogeneous();
+
+        // Normals are transformed by the inverse transpose, so they
+        // stay perpendicular to surfaces which are scaled unevenly
+        let normal_matrix = model
+            .matrix()
+            .fixed_slice::<3, 3>(0, 0)
+            .try_inverse()
+            .unwrap_or_else(Matrix3::identity)
+            .transpose();
+
+        let camera_position = view
+            .try_inverse()
+            .map(|view| view.transform_point(&Point3::origin()))
+            .unwrap_or_else(Point3::origin);
+
+        Self {
+            model: *model,
+            matrix,
+            normal_matrix,
+            camera_position,
+        }
+    }
+
+    pub fn model(&self) -> &Transform3<f32> {
+        &self.model
+    }
+
+    pub fn matrix(&self) -> &Matrix4<f32> {
+        &self.matrix
+    }
+
+    // In world space
+    pub fn camera_position(&self) -> &Point3<f32> {
+        &self.camera_position
+    }
+
+    // Takes a point in model space to clip space
+    pub fn transform_point(&self, point: &Point3<f32>) -> Vector4<f32> {
+        self.matrix * point.to_homogeneous()
+    }
+
+    // Takes a normal in model space to world space
+    pub fn transform_normal(&self, normal: &Vector3<f32>) -> Vector3<f32> {
+        (self.normal_matrix * normal).normalize()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_PI_2;
+
+    use nalgebra::{Rotation3, Translation3, Vector3};
+
+    use super::*;
+
+    // Square, with a 90 degree field of view, so points in view
+    // space land at x / -z and y / -z after the perspective divide
+    fn projection() -> Perspective3<f32> {
+        Perspective3::new(1.0, FRAC_PI_2, 1.0, 100.0)
+    }
+
+    fn ndc(mvp: &ModelViewProjection, point: Point3<f32>) -> (f32, f32, f32) {
+        let clip = mvp.transform_point(&point);
+        (clip.x / clip.w, clip.y / clip.w, clip.w)
+    }
+
+    fn assert_near(actual: (f32, f32, f32), expected: (f32, f32, f32)) {
+        let error = (actual.0 - expected.0)
+            .abs()
+            .max((actual.1 - expected.1).abs())
+            .max((actual.2 - expected.2).abs());
+        assert!(error < 1e-5, "{:?} != {:?}", actual, expected);
+    }
+
+    #[test]
+    fn translated_model() {
+        let model = Transform3::identity() * Translation3::new(1.0, 2.0, 0.0);
+        // The camera sits at z = 4, looking down -z
+        let view = Transform3::identity() * Translation3::new(0.0, 0.0, -4.0);
+        let mvp = ModelViewProjection::new(&model, &view, &projection());
+
+        assert_near(ndc(&mvp, Point3::origin()), (0.25, 0.5, 4.0));
+        assert_near(ndc(&mvp, Point3::new(1.0, 0.0, 0.0)), (0.5, 0.5, 4.0));
+    }
+
+    #[test]
+    fn rotated_and_translated_model() {
+        // Rotates a quarter turn around y, then moves the model away
+        let model = Transform3::identity()
+            * Translation3::new(0.0, 0.0, -2.0)
+            * Rotation3::new(Vector3::y() * FRAC_PI_2);
+        // The camera sits at z = 1, so the model's origin is 3 units ahead.
+        // Applying the view before the model would rotate the camera's
+        // offset as well, and move the model off to the side.
+        let view = Transform3::identity() * Translation3::new(0.0, 0.0, -1.0);
+        let mvp = ModelViewProjection::new(&model, &view, &projection());
+
+        assert_near(ndc(&mvp, Point3::origin()), (0.0, 0.0, 3.0));
+        // +x turns to -z, further from the camera
+        assert_near(ndc(&mvp, Point3::new(1.0, 0.0, 0.0)), (0.0, 0.0, 4.0));
+        // +z turns to +x
+        assert_near(ndc(&mvp, Point3::new(0.0, 0.0, 1.0)), (1.0 / 3.0, 0.0, 3.0));
+    }
+
+    #[test]
+    fn normals_stay_perpendicular_when_scaled() {
+        // Squashes a 45 degree slope, which makes it steeper
+        let model = Transform3::from_matrix_unchecked(Matrix4::new_nonuniform_scaling(
+            &Vector3::new(1.0, 0.5, 1.0),
+        ));
+        let view = Transform3::identity();
+        let mvp = ModelViewProjection::new(&model, &view, &projection());
+
+        let slope = Vector3::new(1.0, 1.0, 0.0);
+        let normal = Vector3::new(-1.0, 1.0, 0.0).normalize();
+        let slope = model.transform_vector(&slope);
+        let normal = mvp.transform_normal(&normal);
+
+        assert!(slope.dot(&normal).abs() < 1e-5);
+        assert!((normal.norm() - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn camera_position_in_world_space() {
+        let model = Transform3::identity();
+        let view = Transform3::identity()
+            * Rotation3::new(Vector3::y() * FRAC_PI_2)
+            * Translation3::new(-1.0, -2.0, -3.0);
+        let mvp = ModelViewProjection::new(&model, &view, &projection());
+
+        assert!((mvp.camera_position() - Point3::new(1.0, 2.0, 3.0)).norm() < 1e-5);
+    }
+}
@@ -1,24 +1,55 @@
 use std::ops::{AddAssign, Div, Mul, MulAssign, Sub};
 
 use gamercade_rs::prelude::GraphicsParameters;
-use nalgebra::{Point3, SVector, Vector4};
+use nalgebra::{Point3, SVector, Vector3, Vector4};
+
+// A region of pixels, the end values are exclusive
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Rect {
+    pub x_start: i32,
+    pub y_start: i32,
+    pub x_end: i32,
+    pub y_end: i32,
+}
+
+impl Rect {
+    pub fn intersect(self, other: Rect) -> Rect {
+        Rect {
+            x_start: self.x_start.max(other.x_start),
+            y_start: self.y_start.max(other.y_start),
+            x_end: self.x_end.min(other.x_end),
+            y_end: self.y_end.min(other.y_end),
+        }
+    }
+
+    pub fn contains(&self, x: i32, y: i32) -> bool {
+        (self.x_start..self.x_end).contains(&x) && (self.y_start..self.y_end).contains(&y)
+    }
+}
 
 pub struct TriangleEdge(pub usize, pub usize);
 
+#[derive(Clone, Copy)]
 pub struct IndexedTriangle(pub usize, pub usize, pub usize);
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub struct Color {
     pub r: u8,
     pub g: u8,
     pub b: u8,
+    // Opacity, only used when blending
+    pub a: u8,
 }
 
 // static mut COLOR_LOOKUP: MaybeUninit<[i32; 32 * 32 * 16]> = MaybeUninit::uninit();
 
 impl Color {
     pub const fn new(r: u8, g: u8, b: u8) -> Self {
-        Self { r, g, b }
+        Self { r, g, b, a: 255 }
+    }
+
+    pub const fn rgba(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self { r, g, b, a }
     }
 
     pub fn to_graphics_params(self) -> GraphicsParameters {
@@ -32,8 +63,8 @@ impl Color {
         let r_palette = a_level * 8;
 
         GraphicsParameters::default()
-            .palette_index(r_palette + g_palette as u8)
-            .color_index(g_color + b_level as u8)
+            .palette_index(r_palette + g_palette)
+            .color_index(g_color + b_level)
     }
 }
 
@@ -41,6 +72,10 @@ pub struct Triangle<const IN: usize> {
     pub vertices: [TriangleVertex<IN>; 3],
 }
 
+pub struct Line<const IN: usize> {
+    pub vertices: [TriangleVertex<IN>; 2],
+}
+
 impl<const D: usize> Triangle<D> {
     pub fn to_ref(&'_ self) -> TriangleRef<'_, D> {
         TriangleRef {
@@ -57,6 +92,8 @@ pub struct TriangleRef<'a, const D: usize> {
 #[derive(Clone)]
 pub struct RawPoint<const IN: usize> {
     pub position: Point3<f32>,
+    // Points away from the surface, used for lighting
+    pub normal: Vector3<f32>,
     pub parameters: SVector<f32, IN>,
 }
 
@@ -66,6 +103,15 @@ pub struct TriangleVertex<const IN: usize> {
     pub parameters: SVector<f32, IN>,
 }
 
+impl<const D: usize> TriangleVertex<D> {
+    pub fn lerp(&self, rhs: &Self, t: f32) -> Self {
+        Self {
+            position: self.position.lerp(&rhs.position, t),
+            parameters: self.parameters.lerp(&rhs.parameters, t),
+        }
+    }
+}
+
 impl<const D: usize> Sub for &TriangleVertex<D> {
     type Output = TriangleVertex<D>;
 